@@ -0,0 +1,139 @@
+//! This crate offers `#[derive(ArrayConvert)]`, which generates conversions
+//! between a struct whose fields all have the same type and the equivalent
+//! array and tuple.
+//!
+//! It's usually used through the `derive` feature of `arraytools`, which
+//! re-exports the macro as `arraytools::ArrayConvert`.
+
+extern crate proc_macro;
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Member};
+
+/// Derives conversions between a struct with homogeneous fields and
+/// the equivalent array and tuple.
+///
+/// For a struct with `N` fields all of type `T`, this generates:
+/// - `From<[T; N]>` for the struct, and `From<Struct>` for `[T; N]`
+/// - `From<(T, T, ..., T)>` for the struct, and `From<Struct>` for the tuple
+///   (the tuple being `<[T; N] as ArrayTools>::Tuple`)
+/// - `as_ref_array(&self) -> [&T; N]` and `as_mut_array(&mut self) -> [&mut T; N]`
+/// - `map_fields(self, f) -> Self`, which applies `f` to every field
+///
+/// Fields are taken in declaration order.  Both named-field and tuple structs
+/// are supported, but enums, unions and structs without fields aren't.
+///
+/// ```rust
+/// use arraytools_derive::ArrayConvert;
+///
+/// #[derive(ArrayConvert, Debug, PartialEq)]
+/// struct Rgb {
+///     r: u8,
+///     g: u8,
+///     b: u8,
+/// }
+///
+/// let teal = Rgb::from([0, 128, 128]);
+/// assert_eq!(teal, Rgb { r: 0, g: 128, b: 128 });
+/// assert_eq!(teal.as_ref_array(), [&0, &128, &128]);
+///
+/// let inverted = teal.map_fields(|x| 255 - x);
+/// assert_eq!(<(u8, u8, u8)>::from(inverted), (255, 127, 127));
+/// ```
+///
+/// The fields only need to have the same type, not the same spelling, but
+/// structs whose fields really do differ are rejected:
+///
+/// ```rust,compile_fail
+/// use arraytools_derive::ArrayConvert;
+///
+/// #[derive(ArrayConvert)]
+/// struct Mixed {
+///     a: u8,
+///     b: u16,
+/// }
+/// ```
+#[proc_macro_derive(ArrayConvert)]
+pub fn derive_array_convert(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> Result<TokenStream, Error> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => return Err(Error::new_spanned(input, "`ArrayConvert` can only be derived for structs")),
+    };
+    let element = match fields.iter().next() {
+        Some(first) => &first.ty,
+        None => return Err(Error::new_spanned(input, "`ArrayConvert` needs a struct with at least one field")),
+    };
+    // The field types aren't compared here, as equal types can be spelled
+    // differently; building `[#element; #n]` from the fields checks them.
+
+    let members: Vec<Member> = match fields {
+        Fields::Named(named) => named.named.iter().map(|f| Member::Named(f.ident.clone().unwrap())).collect(),
+        _ => (0..fields.len()).map(Member::from).collect(),
+    };
+    let vars: Vec<_> = (0..members.len()).map(|i| format_ident!("__field{}", i, span = Span::call_site())).collect();
+    let n = members.len();
+    let elements = vec![element; n];
+
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::core::convert::From<[#element; #n]> for #name #type_generics #where_clause {
+            fn from(array: [#element; #n]) -> Self {
+                let [#(#vars),*] = array;
+                #name { #(#members: #vars),* }
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<#name #type_generics> for [#element; #n] #where_clause {
+            fn from(value: #name #type_generics) -> Self {
+                [#(value.#members),*]
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<(#(#elements,)*)> for #name #type_generics #where_clause {
+            fn from(tuple: (#(#elements,)*)) -> Self {
+                let (#(#vars,)*) = tuple;
+                #name { #(#members: #vars),* }
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<#name #type_generics> for (#(#elements,)*) #where_clause {
+            fn from(value: #name #type_generics) -> Self {
+                (#(value.#members,)*)
+            }
+        }
+
+        impl #impl_generics #name #type_generics #where_clause {
+            /// Builds an array of references to the fields of this struct.
+            #[allow(dead_code)]
+            pub fn as_ref_array(&self) -> [&#element; #n] {
+                [#(&self.#members),*]
+            }
+
+            /// Builds an array of mutable references to the fields of this struct.
+            #[allow(dead_code)]
+            pub fn as_mut_array(&mut self) -> [&mut #element; #n] {
+                [#(&mut self.#members),*]
+            }
+
+            /// Builds a new value by applying the provided function to each field.
+            #[allow(dead_code)]
+            #[must_use = "this returns the new value; it doesn't update the existing one"]
+            pub fn map_fields<F>(self, f: F) -> Self
+                where F: ::core::ops::FnMut(#element) -> #element
+            {
+                let array: [#element; #n] = ::core::convert::From::from(self);
+                ::core::convert::From::from(array.map(f))
+            }
+        }
+    })
+}
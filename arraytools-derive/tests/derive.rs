@@ -0,0 +1,61 @@
+use arraytools_derive::ArrayConvert;
+
+#[derive(ArrayConvert, Debug, PartialEq)]
+struct Pair(i32, i32);
+
+#[derive(ArrayConvert, Debug, PartialEq)]
+struct Single {
+    only: String,
+}
+
+#[derive(ArrayConvert, Debug, PartialEq)]
+struct Wide(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8);
+
+type Byte = u8;
+
+#[derive(ArrayConvert, Debug, PartialEq)]
+struct Spelled {
+    plain: u8,
+    path: core::primitive::u8,
+    alias: Byte,
+}
+
+#[derive(ArrayConvert, Debug, PartialEq)]
+struct Point<T: Copy> {
+    x: T,
+    y: T,
+}
+
+#[test]
+fn tuple_structs_convert_both_ways() {
+    let pair = Pair::from((1, 2));
+    assert_eq!(pair, Pair(1, 2));
+    assert_eq!(<[i32; 2]>::from(pair), [1, 2]);
+}
+
+#[test]
+fn single_field_structs_use_one_tuples() {
+    let mut single = Single::from(("a".to_string(),));
+    single.as_mut_array()[0].push('b');
+    assert_eq!(single.map_fields(|s| s + "c").only, "abc");
+}
+
+#[test]
+fn generic_structs_keep_their_parameters() {
+    let point: Point<f32> = [1.0, 2.0].into();
+    assert_eq!(point.map_fields(|v| v * 2.0), Point { x: 2.0, y: 4.0 });
+    let tuple: (u8, u8) = Point { x: 3, y: 4 }.into();
+    assert_eq!(tuple, (3, 4));
+}
+
+#[test]
+fn structs_with_more_than_32_fields_can_map() {
+    let wide = Wide::from([1; 40]).map_fields(|x| x + 1);
+    assert_eq!(<[u8; 40]>::from(wide), [2; 40]);
+}
+
+#[test]
+fn the_same_type_can_be_spelled_differently() {
+    let spelled = Spelled::from([1, 2, 3]);
+    assert_eq!(spelled.as_ref_array(), [&1, &2, &3]);
+}
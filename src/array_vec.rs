@@ -0,0 +1,495 @@
+use core::iter::{FromIterator, FusedIterator};
+use core::ops::{Bound, RangeBounds};
+use core::{array, fmt, iter, slice};
+
+use crate::traits::ArrayVecStorage;
+
+/// A vector with a fixed capacity, which keeps its elements inline
+/// without allocating.
+///
+/// `ArrayVec<[T; N]>` holds up to `N` elements.  It's for the places where
+/// an operation produces "up to `N`" elements, which an array can't hold,
+/// and it converts back to a full array with [`.into_array()`](#method.into_array).
+///
+/// It's built entirely on safe code, as slots of `Option<T>`, so (unlike a
+/// `Vec`) it can't be viewed as a slice of `T`; use [`.iter()`](#method.iter)
+/// and friends instead.
+///
+/// ```rust
+/// use arraytools::{ArrayTools, ArrayVec};
+///
+/// let readings = [3, -1, 4, -1, 5, 9];
+/// let valid: ArrayVec<[i32; 6]> = readings.iter().copied().filter(|&x| x >= 0).collect();
+/// assert_eq!(valid.len(), 4);
+/// assert!(valid.iter().eq(&[3, 4, 5, 9]));
+///
+/// let mut top = ArrayVec::<[i32; 3]>::new();
+/// for x in valid {
+///     if !top.is_full() {
+///         top.push(x);
+///     }
+/// }
+/// assert_eq!(top.into_array().map(ArrayTools::into_tuple), Ok((3, 4, 5)));
+/// ```
+pub struct ArrayVec<A: ArrayVecStorage> {
+    slots: A::Slots,
+    len: usize,
+}
+
+impl<T, const N: usize> ArrayVec<[T; N]> {
+    /// Creates an empty `ArrayVec`.
+    ///
+    /// ```rust
+    /// use arraytools::ArrayVec;
+    ///
+    /// let v = ArrayVec::<[String; 4]>::new();
+    /// assert!(v.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        ArrayVec { slots: array::from_fn(|_| None), len: 0 }
+    }
+
+    /// Returns the number of elements in this `ArrayVec`.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether this `ArrayVec` has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns whether this `ArrayVec` has `N` elements, so can't hold any more.
+    pub fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Returns the number of elements this `ArrayVec` can hold, which is `N`.
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Appends an element to the back of this `ArrayVec`.
+    ///
+    /// Panics if it's already full; see [`.try_push()`](#method.try_push)
+    /// for a version that doesn't.
+    ///
+    /// ```rust
+    /// use arraytools::ArrayVec;
+    ///
+    /// let mut v = ArrayVec::<[char; 2]>::new();
+    /// v.push('a');
+    /// assert_eq!(v.get(0), Some(&'a'));
+    /// ```
+    pub fn push(&mut self, item: T) {
+        if self.try_push(item).is_err() {
+            panic!("cannot push to a full ArrayVec with capacity {}", N);
+        }
+    }
+
+    /// Appends an element to the back of this `ArrayVec`, or returns it
+    /// if this is already full.
+    ///
+    /// ```rust
+    /// use arraytools::ArrayVec;
+    ///
+    /// let mut v = ArrayVec::<[char; 1]>::new();
+    /// assert_eq!(v.try_push('a'), Ok(()));
+    /// assert_eq!(v.try_push('b'), Err('b'));
+    /// ```
+    pub fn try_push(&mut self, item: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(item);
+        }
+        self.slots[self.len] = Some(item);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Removes the last element from this `ArrayVec` and returns it,
+    /// or returns `None` if it's empty.
+    ///
+    /// ```rust
+    /// use arraytools::ArrayVec;
+    ///
+    /// let mut v = ArrayVec::from([1, 2]);
+    /// assert_eq!(v.pop(), Some(2));
+    /// assert_eq!(v.pop(), Some(1));
+    /// assert_eq!(v.pop(), None);
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        self.len -= 1;
+        self.slots[self.len].take()
+    }
+
+    /// Inserts an element at position `index`, shifting the elements
+    /// after it to the right.
+    ///
+    /// Panics if `index > len`, or if this is already full.
+    ///
+    /// ```rust
+    /// use arraytools::ArrayVec;
+    ///
+    /// let mut v = ArrayVec::<[i32; 4]>::new();
+    /// v.push(1);
+    /// v.push(3);
+    /// v.insert(1, 2);
+    /// assert!(v.iter().eq(&[1, 2, 3]));
+    /// ```
+    pub fn insert(&mut self, index: usize, item: T) {
+        assert!(index <= self.len, "insertion index (is {}) should be <= len (is {})", index, self.len);
+        self.push(item);
+        self.slots[index..self.len].rotate_right(1);
+    }
+
+    /// Removes and returns the element at position `index`, shifting the
+    /// elements after it to the left.
+    ///
+    /// Panics if `index >= len`.
+    ///
+    /// ```rust
+    /// use arraytools::ArrayVec;
+    ///
+    /// let mut v = ArrayVec::from(['a', 'b', 'c']);
+    /// assert_eq!(v.remove(0), 'a');
+    /// assert!(v.iter().eq(&['b', 'c']));
+    /// ```
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(index < self.len, "removal index (is {}) should be < len (is {})", index, self.len);
+        self.slots[index..self.len].rotate_left(1);
+        // The element at `index` was moved to the last occupied slot.
+        self.pop().unwrap()
+    }
+
+    /// Removes every element.
+    pub fn clear(&mut self) {
+        self.drain(..);
+    }
+
+    /// Removes the elements in the provided range, returning them
+    /// as an iterator.
+    ///
+    /// The elements after the range are shifted down when the iterator is
+    /// dropped, and any elements of the range it didn't yield are dropped then too.
+    ///
+    /// Panics if the range is out of bounds.
+    ///
+    /// ```rust
+    /// use arraytools::ArrayVec;
+    ///
+    /// let mut v = ArrayVec::from([1, 2, 3, 4, 5]);
+    /// assert!(v.drain(1..3).eq([2, 3]));
+    /// assert!(v.iter().eq(&[1, 4, 5]));
+    /// ```
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T> {
+        let start = match range.start_bound() {
+            Bound::Included(&i) => i,
+            Bound::Excluded(&i) => i + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&i) => i + 1,
+            Bound::Excluded(&i) => i,
+            Bound::Unbounded => self.len,
+        };
+        assert!(start <= end, "drain range starts (at {}) after it ends (at {})", start, end);
+        assert!(end <= self.len, "drain range ends (at {}) after len (is {})", end, self.len);
+
+        let len = self.len;
+        // Until the `Drain` is dropped, only the elements before the range count.
+        self.len = start;
+        Drain { slots: &mut self.slots[start..len], removed: end - start, front: 0, back: end - start, len: &mut self.len }
+    }
+
+    /// Returns a reference to the element at position `index`,
+    /// or `None` if it's out of bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.slots[..self.len].get(index)?.as_ref()
+    }
+
+    /// Returns a mutable reference to the element at position `index`,
+    /// or `None` if it's out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.slots[..self.len].get_mut(index)?.as_mut()
+    }
+
+    /// Returns an iterator over references to the elements.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { slots: self.slots[..self.len].iter() }
+    }
+
+    /// Returns an iterator over mutable references to the elements.
+    ///
+    /// ```rust
+    /// use arraytools::ArrayVec;
+    ///
+    /// let mut v = ArrayVec::from([1, 2]);
+    /// v.iter_mut().for_each(|x| *x *= 10);
+    /// assert!(v.iter().eq(&[10, 20]));
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut { slots: self.slots[..self.len].iter_mut() }
+    }
+
+    /// Converts this into an array if it's full, or returns it unchanged if it isn't.
+    ///
+    /// ```rust
+    /// use arraytools::ArrayVec;
+    ///
+    /// let mut v = ArrayVec::<[u8; 2]>::new();
+    /// v.push(1);
+    /// let mut v = v.into_array().unwrap_err();
+    /// v.push(2);
+    /// assert_eq!(v.into_array().ok(), Some([1, 2]));
+    /// ```
+    pub fn into_array(self) -> Result<[T; N], Self> {
+        if !self.is_full() {
+            return Err(self);
+        }
+        // Every slot is occupied, so none of these unwraps can fail.
+        Ok(self.slots.map(Option::unwrap))
+    }
+}
+
+impl<T, const N: usize> Default for ArrayVec<[T; N]> {
+    fn default() -> Self {
+        ArrayVec::new()
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for ArrayVec<[T; N]> {
+    fn clone(&self) -> Self {
+        ArrayVec { slots: self.slots.clone(), len: self.len }
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for ArrayVec<[T; N]> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for ArrayVec<[T; N]> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for ArrayVec<[T; N]> {}
+
+/// Builds a full `ArrayVec` from an array.
+impl<T, const N: usize> From<[T; N]> for ArrayVec<[T; N]> {
+    fn from(array: [T; N]) -> Self {
+        ArrayVec { slots: array.map(Some), len: N }
+    }
+}
+
+/// Collects up to `N` items.
+///
+/// Panics if the iterator has more than `N` items.
+impl<T, const N: usize> FromIterator<T> for ArrayVec<[T; N]> {
+    fn from_iter<I: IntoIterator<Item = T>>(it: I) -> Self {
+        let mut v = ArrayVec::new();
+        v.extend(it);
+        v
+    }
+}
+
+/// Appends every item, panicking if there are more than fit.
+impl<T, const N: usize> Extend<T> for ArrayVec<[T; N]> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, it: I) {
+        for item in it {
+            self.push(item);
+        }
+    }
+}
+
+impl<T, const N: usize> IntoIterator for ArrayVec<[T; N]> {
+    type Item = T;
+    type IntoIter = IntoIter<T, N>;
+    fn into_iter(self) -> Self::IntoIter {
+        // Slots past `len` can still hold items, if a `Drain` was leaked.
+        IntoIter { slots: IntoIterator::into_iter(self.slots).take(self.len) }
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a ArrayVec<[T; N]> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut ArrayVec<[T; N]> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// An iterator over references to the elements of an [`ArrayVec`].
+///
+/// This is created by [`ArrayVec::iter`].
+///
+/// [`ArrayVec`]: struct.ArrayVec.html
+/// [`ArrayVec::iter`]: struct.ArrayVec.html#method.iter
+pub struct Iter<'a, T> {
+    /// The occupied slots, which are all `Some`.
+    slots: slice::Iter<'a, Option<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.slots.next()?.as_ref()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.slots.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        self.slots.next_back()?.as_ref()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+impl<'a, T> FusedIterator for Iter<'a, T> {}
+
+impl<'a, T> Clone for Iter<'a, T> {
+    fn clone(&self) -> Self {
+        Iter { slots: self.slots.clone() }
+    }
+}
+
+/// An iterator over mutable references to the elements of an [`ArrayVec`].
+///
+/// This is created by [`ArrayVec::iter_mut`].
+///
+/// [`ArrayVec`]: struct.ArrayVec.html
+/// [`ArrayVec::iter_mut`]: struct.ArrayVec.html#method.iter_mut
+pub struct IterMut<'a, T> {
+    /// The occupied slots, which are all `Some`.
+    slots: slice::IterMut<'a, Option<T>>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.slots.next()?.as_mut()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.slots.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        self.slots.next_back()?.as_mut()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IterMut<'a, T> {}
+
+impl<'a, T> FusedIterator for IterMut<'a, T> {}
+
+/// An iterator moving the elements out of an [`ArrayVec`].
+///
+/// This is created by the `into_iter` method of [`ArrayVec`]
+/// (provided by the `IntoIterator` trait).
+///
+/// [`ArrayVec`]: struct.ArrayVec.html
+pub struct IntoIter<T, const N: usize> {
+    /// The occupied slots, which are all `Some`.
+    slots: iter::Take<array::IntoIter<Option<T>, N>>,
+}
+
+impl<T, const N: usize> Iterator for IntoIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.slots.next()?
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.slots.size_hint()
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+    fn next_back(&mut self) -> Option<T> {
+        self.slots.next_back()?
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {}
+
+impl<T, const N: usize> FusedIterator for IntoIter<T, N> {}
+
+/// An iterator removing a range of elements from an [`ArrayVec`].
+///
+/// This is created by [`ArrayVec::drain`].
+///
+/// [`ArrayVec`]: struct.ArrayVec.html
+/// [`ArrayVec::drain`]: struct.ArrayVec.html#method.drain
+pub struct Drain<'a, T> {
+    /// The slots from the start of the range to the old end of the `ArrayVec`.
+    slots: &'a mut [Option<T>],
+    /// The number of slots at the start of `slots` which are being removed.
+    removed: usize,
+    front: usize,
+    back: usize,
+    len: &'a mut usize,
+}
+
+impl<'a, T> Iterator for Drain<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.front == self.back {
+            return None;
+        }
+        self.front += 1;
+        self.slots[self.front - 1].take()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.back - self.front;
+        (n, Some(n))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Drain<'a, T> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        self.slots[self.back].take()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Drain<'a, T> {}
+
+impl<'a, T> FusedIterator for Drain<'a, T> {}
+
+impl<'a, T> Drop for Drain<'a, T> {
+    fn drop(&mut self) {
+        self.by_ref().for_each(drop);
+        // Every slot of the range is empty now, so moving them to the end
+        // closes the gap.
+        self.slots.rotate_left(self.removed);
+        *self.len += self.slots.len() - self.removed;
+    }
+}
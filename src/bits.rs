@@ -0,0 +1,201 @@
+use crate::traits::{ArrayBits, BitsInt, Sealed};
+
+/// The order in which the elements of a `[bool; N]` map onto bits,
+/// as used by [`BitTools::to_bits`] and friends.
+///
+/// [`BitTools::to_bits`]: trait.BitTools.html#method.to_bits
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BitOrder {
+    /// The first element is the least significant bit.
+    LsbFirst,
+    /// The first element is the most significant bit.
+    MsbFirst,
+}
+
+impl BitOrder {
+    fn shift(self, i: usize, width: usize) -> usize {
+        match self {
+            BitOrder::LsbFirst => i,
+            BitOrder::MsbFirst => width - i - 1,
+        }
+    }
+}
+
+/// An extension trait for packing arrays of `bool`s into integers and bytes.
+///
+/// Use it with
+/// ```rust
+/// use arraytools::BitTools;
+/// ```
+///
+/// It's implemented for `[bool; N]` of every length, though the integer
+/// conversions need `N <= 128`, so that the bits fit in a `u128`.
+///
+/// (This trait is sealed; you are not allowed to implement it yourself.)
+pub trait BitTools: Sized + Sealed {
+    /// Packs this array of `bool`s into the smallest unsigned integer
+    /// with at least `N` bits.
+    ///
+    /// Type: `([bool; N], BitOrder) -> uM`, where `uM` is `u8`, `u16`, `u32`,
+    /// `u64` or `u128`, and `N <= 128`
+    ///
+    /// With [`BitOrder::LsbFirst`] element `i` becomes bit `i`.  With
+    /// [`BitOrder::MsbFirst`] element `0` becomes bit `N-1`, so the
+    /// array reads like the integer written out in binary.
+    ///
+    /// [`BitOrder::LsbFirst`]: enum.BitOrder.html#variant.LsbFirst
+    /// [`BitOrder::MsbFirst`]: enum.BitOrder.html#variant.MsbFirst
+    ///
+    /// ```rust
+    /// use arraytools::{BitOrder, BitTools};
+    ///
+    /// let flags = [true, false, true, true];
+    /// assert_eq!(flags.to_bits(BitOrder::LsbFirst), 0b1101_u8);
+    /// assert_eq!(flags.to_bits(BitOrder::MsbFirst), 0b1011_u8);
+    /// assert_eq!([true; 12].to_bits(BitOrder::LsbFirst), 0x0FFF_u16);
+    /// assert_eq!([true; 100].to_bits(BitOrder::LsbFirst), u128::MAX >> 28);
+    /// ```
+    fn to_bits(self, order: BitOrder) -> <Self as ArrayBits>::Bits
+        where Self: ArrayBits;
+
+    /// Unpacks the low `N` bits of an integer into an array of `bool`s.
+    ///
+    /// Type: `(uM, BitOrder) -> [bool; N]`, where `uM` is `u8`, `u16`, `u32`,
+    /// `u64` or `u128`, and `N <= 128`
+    ///
+    /// This is the inverse of [`.to_bits()`]; any bits above the low `N`
+    /// are ignored.
+    ///
+    /// [`.to_bits()`]: #tymethod.to_bits
+    ///
+    /// ```rust
+    /// use arraytools::{BitOrder, BitTools};
+    ///
+    /// let flags = <[bool; 3]>::from_bits(0b1110, BitOrder::LsbFirst);
+    /// assert_eq!(flags, [false, true, true]);
+    /// let flags = <[bool; 3]>::from_bits(0b1110, BitOrder::MsbFirst);
+    /// assert_eq!(flags, [true, true, false]);
+    /// ```
+    fn from_bits(bits: <Self as ArrayBits>::Bits, order: BitOrder) -> Self
+        where Self: ArrayBits;
+
+    /// Packs this array of `bool`s into bytes, eight elements per byte.
+    ///
+    /// Type: `([bool; N], BitOrder) -> [u8; M]`, where `M = (N + 7) / 8`
+    ///
+    /// With [`BitOrder::LsbFirst`] element `i` becomes bit `i % 8` of byte
+    /// `i / 8`.  With [`BitOrder::MsbFirst`] it becomes bit `7 - i % 8`
+    /// instead.  Any unused bits in the last byte are zero.
+    ///
+    /// As stable Rust can't write `(N + 7) / 8` in a type, the number of
+    /// bytes `M` is a separate parameter, which is checked at compile time.
+    ///
+    /// [`BitOrder::LsbFirst`]: enum.BitOrder.html#variant.LsbFirst
+    /// [`BitOrder::MsbFirst`]: enum.BitOrder.html#variant.MsbFirst
+    ///
+    /// ```rust
+    /// use arraytools::{BitOrder, BitTools};
+    ///
+    /// let mut flags = [false; 10];
+    /// flags[0] = true;
+    /// flags[9] = true;
+    /// assert_eq!(flags.pack_bits::<2>(BitOrder::LsbFirst), [0b0000_0001, 0b0000_0010]);
+    /// assert_eq!(flags.pack_bits::<2>(BitOrder::MsbFirst), [0b1000_0000, 0b0100_0000]);
+    ///
+    /// let packed: [u8; 125] = [true; 1000].pack_bits(BitOrder::LsbFirst);
+    /// assert_eq!(packed, [0xFF; 125]);
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// use arraytools::{BitOrder, BitTools};
+    ///
+    /// [false; 10].pack_bits::<1>(BitOrder::LsbFirst);
+    /// ```
+    fn pack_bits<const M: usize>(self, order: BitOrder) -> [u8; M];
+
+    /// Unpacks bytes into an array of `bool`s, eight elements per byte.
+    ///
+    /// Type: `([u8; M], BitOrder) -> [bool; N]`, where `M = (N + 7) / 8`
+    ///
+    /// This is the inverse of [`.pack_bits()`]; any unused bits in the
+    /// last byte are ignored.
+    ///
+    /// [`.pack_bits()`]: #tymethod.pack_bits
+    ///
+    /// ```rust
+    /// use arraytools::{BitOrder, BitTools};
+    ///
+    /// let flags = <[bool; 9]>::unpack_bits([0b1000_0001, 0b1], BitOrder::LsbFirst);
+    /// assert_eq!(flags, [true, false, false, false, false, false, false, true, true]);
+    /// ```
+    fn unpack_bits<const M: usize>(packed: [u8; M], order: BitOrder) -> Self;
+}
+
+impl<const N: usize> BitTools for [bool; N] {
+    fn to_bits(self, order: BitOrder) -> <Self as ArrayBits>::Bits
+        where Self: ArrayBits
+    {
+        let mut bits = <Self as ArrayBits>::Bits::ZERO;
+        for (i, &b) in self.iter().enumerate() {
+            if b {
+                bits = bits.with_bit(order.shift(i, N));
+            }
+        }
+        bits
+    }
+
+    fn from_bits(bits: <Self as ArrayBits>::Bits, order: BitOrder) -> Self
+        where Self: ArrayBits
+    {
+        core::array::from_fn(|i| bits.bit(order.shift(i, N)))
+    }
+
+    fn pack_bits<const M: usize>(self, order: BitOrder) -> [u8; M] {
+        const { assert!(M == N.div_ceil(8), "the output must have (N + 7) / 8 bytes") };
+        let mut packed = [0; M];
+        for (i, &b) in self.iter().enumerate() {
+            if b {
+                packed[i / 8] = packed[i / 8].with_bit(order.shift(i % 8, 8));
+            }
+        }
+        packed
+    }
+
+    fn unpack_bits<const M: usize>(packed: [u8; M], order: BitOrder) -> Self {
+        const { assert!(M == N.div_ceil(8), "the input must have (N + 7) / 8 bytes") };
+        core::array::from_fn(|i| packed[i / 8].bit(order.shift(i % 8, 8)))
+    }
+}
+
+macro_rules! impl_bits_int {
+    ($($t:ident)+) => ($(
+        impl BitsInt for $t {
+            const ZERO: Self = 0;
+            fn with_bit(self, i: usize) -> Self {
+                self | (1 << i)
+            }
+            fn bit(self, i: usize) -> bool {
+                (self >> i) & 1 != 0
+            }
+        }
+    )+)
+}
+
+impl_bits_int!(u8 u16 u32 u64 u128);
+
+macro_rules! impl_bits {
+    ($bits:ty => $($n:tt)*) => ($(
+        impl ArrayBits for [bool; $n] {
+            type Bits = $bits;
+        }
+    )*)
+}
+
+impl_bits!(u8 => 0 1 2 3 4 5 6 7 8);
+impl_bits!(u16 => 9 10 11 12 13 14 15 16);
+impl_bits!(u32 => 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32);
+impl_bits!(u64 => 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47 48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63 64);
+impl_bits! {
+    u128 => 65 66 67 68 69 70 71 72 73 74 75 76 77 78 79 80 81 82 83 84 85 86 87 88 89 90 91 92 93 94 95 96
+        97 98 99 100 101 102 103 104 105 106 107 108 109 110 111 112 113 114 115 116 117 118 119 120 121 122 123 124 125 126 127 128
+}
@@ -0,0 +1,95 @@
+use core::array;
+
+/// An iterator over the `K`-element combinations of an array's elements.
+///
+/// This is created by [`ArrayTools::combinations`].
+///
+/// [`ArrayTools::combinations`]: trait.ArrayTools.html#method.combinations
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Combinations<T, const N: usize, const K: usize> {
+    array: [T; N],
+    indices: [usize; K],
+    done: bool,
+}
+
+impl<T, const N: usize, const K: usize> Combinations<T, N, K> {
+    pub(crate) fn new(array: [T; N]) -> Self {
+        Combinations { array, indices: array::from_fn(|i| i), done: K > N }
+    }
+}
+
+impl<T: Clone, const N: usize, const K: usize> Iterator for Combinations<T, N, K> {
+    type Item = [T; K];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let item = array::from_fn(|i| self.array[self.indices[i]].clone());
+
+        // Advance to the next combination in lexicographic order of indices:
+        // bump the rightmost index that isn't already at its maximum,
+        // then pack the ones after it immediately behind it.
+        match (0..K).rev().find(|&i| self.indices[i] != i + N - K) {
+            Some(i) => {
+                self.indices[i] += 1;
+                for j in i + 1..K {
+                    self.indices[j] = self.indices[j - 1] + 1;
+                }
+            }
+            None => self.done = true,
+        }
+        Some(item)
+    }
+}
+
+/// An iterator over the permutations of an array's elements.
+///
+/// This is created by [`ArrayTools::permutations`].
+///
+/// [`ArrayTools::permutations`]: trait.ArrayTools.html#method.permutations
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Permutations<T, const N: usize> {
+    array: [T; N],
+    counters: [usize; N],
+    level: usize,
+    started: bool,
+}
+
+impl<T, const N: usize> Permutations<T, N> {
+    pub(crate) fn new(array: [T; N]) -> Self {
+        Permutations { array, counters: [0; N], level: 1, started: false }
+    }
+}
+
+impl<T: Clone, const N: usize> Iterator for Permutations<T, N> {
+    type Item = [T; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.started {
+            self.started = true;
+            return Some(self.array.clone());
+        }
+
+        // This is the iterative form of Heap's algorithm, where each
+        // permutation differs from the previous one by a single swap.
+        while self.level < N {
+            let i = self.level;
+            if self.counters[i] < i {
+                if i % 2 == 0 {
+                    self.array.swap(0, i);
+                } else {
+                    self.array.swap(self.counters[i], i);
+                }
+                self.counters[i] += 1;
+                self.level = 1;
+                return Some(self.array.clone());
+            }
+            self.counters[i] = 0;
+            self.level += 1;
+        }
+        None
+    }
+}
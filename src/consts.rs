@@ -0,0 +1,167 @@
+//! `const fn` versions of some [`ArrayTools`] methods, for use in `const`
+//! and `static` items, where trait methods can't be called.
+//!
+//! The most common ones are also re-exported at the crate root.  The tuple
+//! conversions, of which there's one for each length up to 32, are only here.
+//!
+//! [`ArrayTools`]: ../trait.ArrayTools.html
+
+/// Builds the array `[0, 1, 2, ..., N-1]` in a `const` context.
+///
+/// Type: `() -> [usize; N]`
+///
+/// This is the `const` version of
+/// [`ArrayTools::indices`](../trait.ArrayTools.html#method.indices).
+///
+/// ```rust
+/// static TABLE: [usize; 16] = arraytools::const_indices();
+/// assert_eq!(TABLE[15], 15);
+/// ```
+pub const fn const_indices<const N: usize>() -> [usize; N] {
+    let mut array = [0; N];
+    let mut i = 0;
+    while i < N {
+        array[i] = i;
+        i += 1;
+    }
+    array
+}
+
+/// Builds an array by copying the provided value, in a `const` context.
+///
+/// Type: `T -> [T; N]`, where `T: Copy`
+///
+/// This is the `const` version of
+/// [`ArrayTools::repeat`](../trait.ArrayTools.html#method.repeat),
+/// for `Copy` types.
+///
+/// ```rust
+/// const ROW: [Option<u8>; 4] = arraytools::const_repeat(None);
+/// assert_eq!(ROW, [None; 4]);
+/// ```
+pub const fn const_repeat<T: Copy, const N: usize>(x: T) -> [T; N] {
+    [x; N]
+}
+
+/// Appends an item to the end of an array, in a `const` context.
+///
+/// Type: `([T; N], T) -> [T; N+1]`, where `T: Copy`
+///
+/// This is the `const` version of
+/// [`ArrayTools::push_back`](../trait.ArrayTools.html#method.push_back),
+/// for `Copy` types.  As stable Rust can't write `N + 1` in a type,
+/// the output length `M` is a separate parameter, which is checked
+/// at compile time to be `N + 1`.
+///
+/// ```rust
+/// const PRIMES: [u32; 4] = arraytools::const_push_back([2, 3, 5], 7);
+/// assert_eq!(PRIMES, [2, 3, 5, 7]);
+/// ```
+///
+/// ```rust,compile_fail
+/// const WRONG: [u32; 5] = arraytools::const_push_back([2, 3, 5], 7);
+/// ```
+pub const fn const_push_back<T: Copy, const N: usize, const M: usize>(array: [T; N], item: T) -> [T; M] {
+    const { assert!(M == N + 1, "the output length must be one more than the input length") };
+    let mut output = [item; M];
+    let mut i = 0;
+    while i < N {
+        output[i] = array[i];
+        i += 1;
+    }
+    output
+}
+
+/// Removes the last item from an array, in a `const` context.
+///
+/// Type: `[T; N+1] -> ([T; N], T)`, where `T: Copy`
+///
+/// This is the `const` version of
+/// [`ArrayTools::pop_back`](../trait.ArrayTools.html#method.pop_back),
+/// for `Copy` types.  As with [`const_push_back`], the output length `M`
+/// is a separate parameter, which is checked at compile time to be `N - 1`.
+///
+/// [`const_push_back`]: fn.const_push_back.html
+///
+/// ```rust
+/// const SPLIT: ([char; 2], char) = arraytools::const_pop_back(['a', 'b', 'c']);
+/// assert_eq!(SPLIT, (['a', 'b'], 'c'));
+/// ```
+pub const fn const_pop_back<T: Copy, const N: usize, const M: usize>(array: [T; N]) -> ([T; M], T) {
+    const { assert!(M + 1 == N, "the output length must be one less than the input length") };
+    let last = array[N - 1];
+    let mut output = [last; M];
+    let mut i = 0;
+    while i < M {
+        output[i] = array[i];
+        i += 1;
+    }
+    (output, last)
+}
+
+macro_rules! const_tuple_conversions {
+    ($($n:literal $from:ident $into:ident => $($i:ident)*;)*) => ($(
+        #[doc = concat!("Converts a homogeneous tuple of ", stringify!($n), " elements into an array, in a `const` context.")]
+        ///
+        #[doc = concat!("Type: `(T, ..., T) -> [T; ", stringify!($n), "]`, where `T: Copy`")]
+        ///
+        /// This is the `const` version of
+        /// [`ArrayTools::from_tuple`](../trait.ArrayTools.html#method.from_tuple),
+        /// for `Copy` types.  As a function can't be generic over the length
+        /// of a tuple, there's one of these for each length up to 32.
+        pub const fn $from<T: Copy>(tuple: ($(replace_ident!($i => T),)*)) -> [T; $n] {
+            let ($($i,)*) = tuple;
+            [$($i,)*]
+        }
+
+        #[doc = concat!("Converts an array of ", stringify!($n), " elements into the equivalent tuple, in a `const` context.")]
+        ///
+        #[doc = concat!("Type: `[T; ", stringify!($n), "] -> (T, ..., T)`, where `T: Copy`")]
+        ///
+        /// This is the `const` version of
+        /// [`ArrayTools::into_tuple`](../trait.ArrayTools.html#method.into_tuple),
+        /// for `Copy` types.  As a function can't be generic over the length
+        /// of a tuple, there's one of these for each length up to 32.
+        #[allow(clippy::unused_unit)]
+        pub const fn $into<T: Copy>(array: [T; $n]) -> ($(replace_ident!($i => T),)*) {
+            let [$($i,)*] = array;
+            ($($i,)*)
+        }
+    )*)
+}
+
+const_tuple_conversions! {
+    0 const_from_tuple0 const_into_tuple0 => ;
+    1 const_from_tuple1 const_into_tuple1 => a0;
+    2 const_from_tuple2 const_into_tuple2 => a0 a1;
+    3 const_from_tuple3 const_into_tuple3 => a0 a1 a2;
+    4 const_from_tuple4 const_into_tuple4 => a0 a1 a2 a3;
+    5 const_from_tuple5 const_into_tuple5 => a0 a1 a2 a3 a4;
+    6 const_from_tuple6 const_into_tuple6 => a0 a1 a2 a3 a4 a5;
+    7 const_from_tuple7 const_into_tuple7 => a0 a1 a2 a3 a4 a5 a6;
+    8 const_from_tuple8 const_into_tuple8 => a0 a1 a2 a3 a4 a5 a6 a7;
+    9 const_from_tuple9 const_into_tuple9 => a0 a1 a2 a3 a4 a5 a6 a7 a8;
+    10 const_from_tuple10 const_into_tuple10 => a0 a1 a2 a3 a4 a5 a6 a7 a8 a9;
+    11 const_from_tuple11 const_into_tuple11 => a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10;
+    12 const_from_tuple12 const_into_tuple12 => a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11;
+    13 const_from_tuple13 const_into_tuple13 => a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12;
+    14 const_from_tuple14 const_into_tuple14 => a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13;
+    15 const_from_tuple15 const_into_tuple15 => a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14;
+    16 const_from_tuple16 const_into_tuple16 => a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15;
+    17 const_from_tuple17 const_into_tuple17 => a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15 a16;
+    18 const_from_tuple18 const_into_tuple18 => a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15 a16 a17;
+    19 const_from_tuple19 const_into_tuple19 => a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15 a16 a17 a18;
+    20 const_from_tuple20 const_into_tuple20 => a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15 a16 a17 a18 a19;
+    21 const_from_tuple21 const_into_tuple21 => a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15 a16 a17 a18 a19 a20;
+    22 const_from_tuple22 const_into_tuple22 => a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15 a16 a17 a18 a19 a20 a21;
+    23 const_from_tuple23 const_into_tuple23 => a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15 a16 a17 a18 a19 a20 a21 a22;
+    24 const_from_tuple24 const_into_tuple24 => a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15 a16 a17 a18 a19 a20 a21 a22 a23;
+    25 const_from_tuple25 const_into_tuple25 => a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15 a16 a17 a18 a19 a20 a21 a22 a23 a24;
+    26 const_from_tuple26 const_into_tuple26 => a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15 a16 a17 a18 a19 a20 a21 a22 a23 a24 a25;
+    27 const_from_tuple27 const_into_tuple27 => a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15 a16 a17 a18 a19 a20 a21 a22 a23 a24 a25 a26;
+    28 const_from_tuple28 const_into_tuple28 => a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15 a16 a17 a18 a19 a20 a21 a22 a23 a24 a25 a26 a27;
+    29 const_from_tuple29 const_into_tuple29 => a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15 a16 a17 a18 a19 a20 a21 a22 a23 a24 a25 a26 a27 a28;
+    30 const_from_tuple30 const_into_tuple30 => a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15 a16 a17 a18 a19 a20 a21 a22 a23 a24 a25 a26 a27 a28 a29;
+    31 const_from_tuple31 const_into_tuple31 => a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15 a16 a17 a18 a19 a20 a21 a22 a23 a24 a25 a26 a27 a28 a29 a30;
+    32 const_from_tuple32 const_into_tuple32 => a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15 a16 a17 a18 a19 a20 a21 a22 a23 a24 a25 a26 a27 a28 a29 a30 a31;
+}
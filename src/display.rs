@@ -0,0 +1,136 @@
+use core::fmt;
+
+use crate::traits::ElementFormat;
+
+/// An adaptor for displaying the elements of an array with a separator,
+/// and optionally brackets, a custom element format, or truncation.
+///
+/// This is created by [`ArrayTools::display_with`].
+///
+/// [`ArrayTools::display_with`]: trait.ArrayTools.html#method.display_with
+///
+/// ```rust
+/// use arraytools::ArrayTools;
+///
+/// let samples = [0.5, 0.25, 0.125, 0.0625, 0.03125, 0.015625];
+/// let shown = samples.display_with(", ").prefix("[").suffix("]").truncate(2);
+/// assert_eq!(format!("{:.2}", shown), "[0.50, 0.25, ..., 0.03, 0.02]");
+/// ```
+#[must_use = "this does nothing unless displayed"]
+pub struct DisplayWith<'a, T, F = UseDisplay> {
+    items: &'a [T],
+    separator: &'a str,
+    prefix: &'a str,
+    suffix: &'a str,
+    format: F,
+    truncate: Option<usize>,
+}
+
+/// The default element format for [`DisplayWith`], which uses each
+/// element's `Display` implementation.
+///
+/// [`DisplayWith`]: struct.DisplayWith.html
+#[derive(Debug, Copy, Clone, Default)]
+pub struct UseDisplay;
+
+impl<'a, T> DisplayWith<'a, T> {
+    pub(crate) fn new(items: &'a [T], separator: &'a str) -> Self {
+        DisplayWith { items, separator, prefix: "", suffix: "", format: UseDisplay, truncate: None }
+    }
+}
+
+impl<'a, T, F> DisplayWith<'a, T, F> {
+    /// Sets the text written before the first element.
+    pub fn prefix(self, prefix: &'a str) -> Self {
+        DisplayWith { prefix, ..self }
+    }
+
+    /// Sets the text written after the last element.
+    pub fn suffix(self, suffix: &'a str) -> Self {
+        DisplayWith { suffix, ..self }
+    }
+
+    /// Shows only the first `k` and the last `k` elements, with `...` in
+    /// place of the rest, when there are more than `2 * k` elements.
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// let shown = [1, 2, 3, 4, 5].display_with(" ").truncate(1);
+    /// assert_eq!(shown.to_string(), "1 ... 5");
+    /// let shown = [1, 2, 3, 4].display_with(" ").truncate(2);
+    /// assert_eq!(shown.to_string(), "1 2 3 4");
+    /// ```
+    pub fn truncate(self, k: usize) -> Self {
+        DisplayWith { truncate: Some(k), ..self }
+    }
+
+    /// Uses the provided function to write each element, instead of
+    /// the element's `Display` implementation.
+    ///
+    /// This works for elements which don't implement `Display` at all:
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// let pairs = [(1, 'a'), (2, 'b')];
+    /// let shown = pairs.display_with("; ").format_with(|(n, c), f| write!(f, "{}={}", c, n));
+    /// assert_eq!(shown.to_string(), "a=1; b=2");
+    /// ```
+    pub fn format_with<G>(self, format: G) -> DisplayWith<'a, T, G>
+        where G: Fn(&T, &mut fmt::Formatter) -> fmt::Result
+    {
+        let DisplayWith { items, separator, prefix, suffix, format: _, truncate } = self;
+        DisplayWith { items, separator, prefix, suffix, format, truncate }
+    }
+
+    fn write_items(&self, items: &[T], f: &mut fmt::Formatter) -> fmt::Result
+        where F: ElementFormat<T>
+    {
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                f.write_str(self.separator)?;
+            }
+            self.format.format(item, f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Each element is written with the same `Formatter`, so flags like
+/// precision and width apply to every element individually.
+impl<'a, T, F: ElementFormat<T>> fmt::Display for DisplayWith<'a, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.prefix)?;
+        match self.truncate {
+            Some(k) if k.checked_mul(2).is_some_and(|shown| self.items.len() > shown) => {
+                let (head, tail) = (&self.items[..k], &self.items[self.items.len() - k..]);
+                self.write_items(head, f)?;
+                if k > 0 {
+                    f.write_str(self.separator)?;
+                }
+                f.write_str("...")?;
+                if k > 0 {
+                    f.write_str(self.separator)?;
+                }
+                self.write_items(tail, f)?;
+            }
+            _ => self.write_items(self.items, f)?,
+        }
+        f.write_str(self.suffix)
+    }
+}
+
+impl<T: fmt::Display> ElementFormat<T> for UseDisplay {
+    fn format(&self, item: &T, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(item, f)
+    }
+}
+
+impl<T, F> ElementFormat<T> for F
+    where F: Fn(&T, &mut fmt::Formatter) -> fmt::Result
+{
+    fn format(&self, item: &T, f: &mut fmt::Formatter) -> fmt::Result {
+        self(item, f)
+    }
+}
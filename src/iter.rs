@@ -0,0 +1,221 @@
+use core::{array, iter};
+
+/// An extension trait for collecting iterators into fixed-length arrays.
+///
+/// Use it with
+/// ```rust
+/// use arraytools::IteratorExt;
+/// ```
+///
+/// This is the iterator-side counterpart of
+/// [`ArrayTools::from_iter`](trait.ArrayTools.html#method.from_iter).
+pub trait IteratorExt: Iterator + Sized {
+    /// Collects this iterator into an array, or returns `None` if it
+    /// didn't contain exactly `N` items.
+    ///
+    /// Type: `impl Iterator<Item = T> -> Option<[T; N]>`
+    ///
+    /// ```rust
+    /// use arraytools::IteratorExt;
+    ///
+    /// assert_eq!((1..4).collect_array::<3>(), Some([1, 2, 3]));
+    /// assert_eq!((1..4).collect_array::<2>(), None);
+    /// assert_eq!((1..4).collect_array::<4>(), None);
+    /// ```
+    fn collect_array<const N: usize>(mut self) -> Option<[Self::Item; N]> {
+        let array = self.next_array()?;
+        match self.next() {
+            Some(_) => None,
+            None => Some(array),
+        }
+    }
+
+    /// Pulls the next `N` items out of this iterator as an array,
+    /// or returns `None` if it ran out first.
+    ///
+    /// Type: `&mut impl Iterator<Item = T> -> Option<[T; N]>`
+    ///
+    /// When this returns `None`, the items that were pulled are dropped.
+    ///
+    /// ```rust
+    /// use arraytools::IteratorExt;
+    ///
+    /// let mut it = 1..6;
+    /// assert_eq!(it.next_array::<2>(), Some([1, 2]));
+    /// assert_eq!(it.next_array::<2>(), Some([3, 4]));
+    /// assert_eq!(it.next_array::<2>(), None);
+    /// ```
+    fn next_array<const N: usize>(&mut self) -> Option<[Self::Item; N]> {
+        fill(self).ok()
+    }
+
+    /// Returns an iterator over `N` items at a time from this iterator.
+    ///
+    /// Type: `impl Iterator<Item = T> -> impl Iterator<Item = [T; N]>`
+    ///
+    /// If the number of items isn't a multiple of `N`, the last few are
+    /// available from [`ArrayChunks::into_remainder`] once this is exhausted.
+    ///
+    /// Panics if `N` is zero.
+    ///
+    /// (`Iterator` has an unstable method with the same name, so calling this
+    /// as `IteratorExt::array_chunks(it)` avoids a future-compatibility warning.)
+    ///
+    /// [`ArrayChunks::into_remainder`]: struct.ArrayChunks.html#method.into_remainder
+    ///
+    /// ```rust
+    /// use arraytools::IteratorExt;
+    ///
+    /// let mut chunks = IteratorExt::array_chunks::<2>("hello".chars());
+    /// assert_eq!(chunks.next(), Some(['h', 'e']));
+    /// assert_eq!(chunks.next(), Some(['l', 'l']));
+    /// assert_eq!(chunks.next(), None);
+    /// assert!(chunks.into_remainder().eq(['o']));
+    /// ```
+    fn array_chunks<const N: usize>(self) -> ArrayChunks<Self, N> {
+        assert!(N != 0, "chunk size must be non-zero");
+        ArrayChunks { iter: self, remainder: None }
+    }
+
+    /// Returns an iterator over overlapping windows of `N` consecutive items
+    /// from this iterator, cloning each item into the `N` windows containing it.
+    ///
+    /// Type: `impl Iterator<Item = T> -> impl Iterator<Item = [T; N]>`, where `T: Clone`
+    ///
+    /// Only the most recent window is kept, so this never buffers more
+    /// than `N` items.
+    ///
+    /// Panics if `N` is zero.
+    ///
+    /// ```rust
+    /// use arraytools::{ArrayTools, IteratorExt};
+    ///
+    /// let mut windows = (1..6).array_windows::<3>();
+    /// assert_eq!(windows.next(), Some([1, 2, 3]));
+    /// assert_eq!(windows.next(), Some([2, 3, 4]));
+    /// assert_eq!(windows.next(), Some([3, 4, 5]));
+    /// assert_eq!(windows.next(), None);
+    ///
+    /// let sums = [1, 2, 3, 4].iter().copied().array_windows::<2>()
+    ///     .map(|w| w.as_slice().iter().sum::<i32>());
+    /// assert!(sums.eq([3, 5, 7]));
+    /// ```
+    fn array_windows<const N: usize>(self) -> ArrayWindows<Self, N>
+        where Self::Item: Clone
+    {
+        assert!(N != 0, "window size must be non-zero");
+        ArrayWindows { iter: self, window: None }
+    }
+}
+
+impl<I: Iterator> IteratorExt for I {}
+
+/// An iterator over `N` items at a time from another iterator.
+///
+/// This is created by [`IteratorExt::array_chunks`].
+///
+/// [`IteratorExt::array_chunks`]: trait.IteratorExt.html#method.array_chunks
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ArrayChunks<I: Iterator, const N: usize> {
+    iter: I,
+    remainder: Option<[Option<I::Item>; N]>,
+}
+
+impl<I: Iterator, const N: usize> ArrayChunks<I, N> {
+    /// Returns the items left over at the end, which weren't enough to make
+    /// a whole chunk.
+    ///
+    /// This is empty until the iterator has returned `None`.
+    pub fn into_remainder(self) -> iter::Flatten<array::IntoIter<Option<I::Item>, N>> {
+        let remainder = self.remainder.unwrap_or_else(|| array::from_fn(|_| None));
+        IntoIterator::into_iter(remainder).flatten()
+    }
+}
+
+impl<I: Iterator, const N: usize> Iterator for ArrayChunks<I, N> {
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remainder.is_some() {
+            return None;
+        }
+        match fill(&mut self.iter) {
+            Ok(array) => Some(array),
+            Err(partial) => {
+                self.remainder = Some(partial);
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.remainder.is_some() {
+            return (0, Some(0));
+        }
+        let (lower, upper) = self.iter.size_hint();
+        (lower / N, upper.map(|n| n / N))
+    }
+}
+
+/// An iterator over overlapping windows of `N` items from another iterator.
+///
+/// This is created by [`IteratorExt::array_windows`].
+///
+/// [`IteratorExt::array_windows`]: trait.IteratorExt.html#method.array_windows
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ArrayWindows<I: Iterator, const N: usize> {
+    iter: I,
+    window: Option<[I::Item; N]>,
+}
+
+impl<I: Iterator, const N: usize> Iterator for ArrayWindows<I, N>
+    where I::Item: Clone
+{
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.window {
+            None => {
+                let window = fill(&mut self.iter).ok()?;
+                self.window = Some(window.clone());
+                Some(window)
+            }
+            Some(window) => {
+                let item = self.iter.next()?;
+                window.rotate_left(1);
+                window[N - 1] = item;
+                Some(window.clone())
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        match self.window {
+            None => (lower.saturating_sub(N - 1), upper.map(|n| n.saturating_sub(N - 1))),
+            Some(_) => (lower, upper),
+        }
+    }
+}
+
+/// Pulls the next `N` items from the iterator, returning them as an array
+/// if there were enough, or as a partially-filled buffer if there weren't.
+pub(crate) fn fill<I: Iterator, const N: usize>(it: &mut I) -> Result<[I::Item; N], [Option<I::Item>; N]> {
+    let mut exhausted = false;
+    let buffer: [Option<I::Item>; N] = array::from_fn(|_| {
+        if exhausted {
+            return None;
+        }
+        let item = it.next();
+        exhausted = item.is_none();
+        item
+    });
+    if exhausted {
+        Err(buffer)
+    } else {
+        // Every slot was filled, so none of these unwraps can fail.
+        Ok(buffer.map(Option::unwrap))
+    }
+}
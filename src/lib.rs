@@ -41,6 +41,56 @@
 //! assert_eq!(array, [1, 1, 2, 3, 5]);
 //! ```
 //!
+//! Or starting from the tuple side, with `TupleTools`:
+//!
+//! ```rust
+//! use arraytools::TupleTools;
+//!
+//! assert_eq!((1, 2, 3).into_array(), [1, 2, 3]);
+//! assert_eq!((1, 2, 3).map(|x| x * 2), (2, 4, 6));
+//! ```
+//!
+//! Getting arrays out of slices, with [`SliceTools`]:
+//!
+//! [`SliceTools`]: trait.SliceTools.html
+//!
+//! ```rust
+//! use arraytools::{ArrayTools, SliceTools};
+//!
+//! let bytes: &[u8] = &[0, 1, 0, 2, 0, 3];
+//! let (header, rest) = bytes.split_array_ref::<4>().unwrap();
+//! assert_eq!(<[u16; 2]>::from_be_bytes(*header), [1, 2]);
+//! assert_eq!(<[u8; 2]>::from_slice_cloned(rest), Ok([0, 3]));
+//! ```
+//!
+//! Trait methods can't be called in `const` and `static` items, so a few
+//! methods also have `const fn` versions, such as [`const_indices`] and
+//! [`const_push_back`].  As a function can't be generic over the length of
+//! a tuple, the tuple conversions have one for each length instead, such as
+//! [`consts::const_from_tuple3`] and [`consts::const_into_tuple3`].
+//!
+//! [`const_indices`]: consts/fn.const_indices.html
+//! [`const_push_back`]: consts/fn.const_push_back.html
+//! [`consts::const_from_tuple3`]: consts/fn.const_from_tuple3.html
+//! [`consts::const_into_tuple3`]: consts/fn.const_into_tuple3.html
+//!
+//! ```rust
+//! const SQUARES: [usize; 4] = {
+//!     let mut table = arraytools::const_indices();
+//!     let mut i = 0;
+//!     while i < table.len() {
+//!         table[i] *= table[i];
+//!         i += 1;
+//!     }
+//!     table
+//! };
+//! assert_eq!(SQUARES, [0, 1, 4, 9]);
+//!
+//! const ORIGIN: (f32, f32, f32) = arraytools::consts::const_into_tuple3([0.0; 3]);
+//! const UP: [f32; 3] = arraytools::consts::const_from_tuple3((0.0, 1.0, 0.0));
+//! assert_eq!((ORIGIN, UP), ((0.0, 0.0, 0.0), [0.0, 1.0, 0.0]));
+//! ```
+//!
 //! Like `Option`, most combinators here take `self`.  To not move something,
 //! you can use [`.as_ref_array()`] or [`.as_mut_array()`]:
 //!
@@ -59,8 +109,59 @@
 //! ```
 //!
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::cmp::Ordering;
+use core::str::FromStr;
+use core::{fmt, mem};
+
 use self::traits::*;
 
+pub use self::array_vec::{ArrayVec, Drain, IntoIter, Iter, IterMut};
+pub use self::bits::{BitOrder, BitTools};
+pub use self::combinatorics::{Combinations, Permutations};
+pub use self::consts::{const_indices, const_pop_back, const_push_back, const_repeat};
+pub use self::display::{DisplayWith, UseDisplay};
+pub use self::iter::{ArrayChunks, ArrayWindows, IteratorExt};
+pub use self::mask::MaskTools;
+pub use self::nested::NestedElement;
+pub use self::numeric::{Float, Numeric};
+pub use self::parse::ParseArrayError;
+pub use self::random::RandomSource;
+#[cfg(feature = "rand")]
+pub use self::random::RandCore;
+pub use self::slice::SliceTools;
+pub use self::tuple::TupleTools;
+pub use self::wrapper::Array;
+
+/// Derives conversions between a struct whose fields all have the same type
+/// and the equivalent array and tuple.
+///
+/// Available with the `derive` feature; see the `arraytools-derive` crate.
+#[cfg(feature = "derive")]
+pub use arraytools_derive::ArrayConvert;
+
+macro_rules! replace_ident {
+    ($i:ident => $($j:tt)*) => ($($j)*)
+}
+
+pub mod consts;
+
+mod array_vec;
+mod bits;
+mod combinatorics;
+mod display;
+mod iter;
+mod mask;
+mod nested;
+mod numeric;
+mod parse;
+mod random;
+mod slice;
+mod tuple;
+mod wrapper;
+
 /// An extension trait for working with fixed-length arrays.
 ///
 /// Use it with
@@ -156,6 +257,87 @@ pub trait ArrayTools: Sized + Sealed {
     /// ```
     fn into_tuple(self) -> Self::Tuple;
 
+    /// Converts this array of arrays into the equivalent tuple of tuples.
+    ///
+    /// Type: `[[T; M]; N] -> ((T, ..., T), ..., (T, ..., T))`
+    ///
+    /// This converts exactly two levels, and works with any element type,
+    /// even if it's an array itself.  For three levels, use
+    /// [`.into_nested_tuple3()`](#method.into_nested_tuple3); for any depth,
+    /// with a fixed set of element types, use [`NestedElement`].
+    ///
+    /// [`NestedElement`]: trait.NestedElement.html
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// let matrix = [[1.0, 0.0], [0.0, 1.0]];
+    /// assert_eq!(matrix.into_nested_tuple(), ((1.0, 0.0), (0.0, 1.0)));
+    ///
+    /// let names = [["a".to_string(), "b".to_string()], ["c".to_string(), "d".to_string()]];
+    /// let ((_, b), (c, _)) = names.into_nested_tuple();
+    /// assert_eq!(b + &c, "bc");
+    /// ```
+    fn into_nested_tuple(self) -> <Self as ArrayNested>::Nested
+        where Self: ArrayNested
+    {
+        ArrayNested::into_nested_tuple(self)
+    }
+
+    /// Converts a tuple of tuples into the equivalent array of arrays.
+    ///
+    /// Type: `((T, ..., T), ..., (T, ..., T)) -> [[T; M]; N]`
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// let matrix = <[[f32; 2]; 2]>::from_nested_tuple(((1.0, 2.0), (3.0, 4.0)));
+    /// assert_eq!(matrix, [[1.0, 2.0], [3.0, 4.0]]);
+    /// ```
+    fn from_nested_tuple(tuple: <Self as ArrayNested>::Nested) -> Self
+        where Self: ArrayNested
+    {
+        ArrayNested::from_nested_tuple(tuple)
+    }
+
+    /// Converts this array of arrays of arrays into the equivalent tuple
+    /// of tuples of tuples.
+    ///
+    /// Type: `[[[T; L]; M]; N] -> (((T, ..., T), ...), ..., ((T, ..., T), ...))`
+    ///
+    /// This converts exactly three levels, and works with any element type.
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// let s = |x: &str| x.to_string();
+    /// let cube = [[[s("a"), s("b")], [s("c"), s("d")]], [[s("e"), s("f")], [s("g"), s("h")]]];
+    /// let (((a, _), _), (_, (_, h))) = cube.into_nested_tuple3();
+    /// assert_eq!(a + &h, "ah");
+    /// ```
+    fn into_nested_tuple3(self) -> <Self as ArrayNested3>::Nested
+        where Self: ArrayNested3
+    {
+        ArrayNested3::into_nested_tuple3(self)
+    }
+
+    /// Converts a tuple of tuples of tuples into the equivalent array of
+    /// arrays of arrays.
+    ///
+    /// Type: `(((T, ..., T), ...), ..., ((T, ..., T), ...)) -> [[[T; L]; M]; N]`
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// let cube = <[[[u8; 2]; 1]; 2]>::from_nested_tuple3((((1, 2),), ((3, 4),)));
+    /// assert_eq!(cube, [[[1, 2]], [[3, 4]]]);
+    /// ```
+    fn from_nested_tuple3(tuple: <Self as ArrayNested3>::Nested) -> Self
+        where Self: ArrayNested3
+    {
+        ArrayNested3::from_nested_tuple3(tuple)
+    }
+
     /// Builds an array by calling the provided function.
     ///
     /// Type: `F -> [T; N]`
@@ -218,10 +400,82 @@ pub trait ArrayTools: Sized + Sealed {
         ArrayFromIter::from_iter(it.into_iter())
     }
 
+    /// Builds an array by cloning the elements of a slice of exactly
+    /// the same length, or returns an error saying how long it was.
+    ///
+    /// Type: `&[T] -> Result<[T; N], LengthError>`, where `T: Clone`
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// let names = ["a".to_string(), "b".to_string()];
+    /// assert_eq!(<[String; 2]>::from_slice_cloned(&names), Ok(names.clone()));
+    ///
+    /// let error = <[String; 3]>::from_slice_cloned(&names).unwrap_err();
+    /// assert_eq!((error.expected(), error.actual()), (3, 2));
+    /// ```
+    fn from_slice_cloned(slice: &[Self::Element]) -> Result<Self, LengthError>
+        where Self: ArrayFromSlice<Self::Element>
+    {
+        ArrayFromSlice::from_slice_cloned(slice)
+    }
+
+    /// Builds an array by cloning the first `N` elements of a slice,
+    /// or returns an error if the slice is shorter than that.
+    ///
+    /// Type: `&[T] -> Result<[T; N], LengthError>`, where `T: Clone`
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// let primes = [2, 3, 5, 7, 11];
+    /// assert_eq!(<[i32; 3]>::from_slice_prefix(&primes), Ok([2, 3, 5]));
+    ///
+    /// let error = <[i32; 6]>::from_slice_prefix(&primes).unwrap_err();
+    /// assert_eq!((error.expected(), error.actual()), (6, 5));
+    /// ```
+    fn from_slice_prefix(slice: &[Self::Element]) -> Result<Self, LengthError>
+        where Self: ArrayFromSlice<Self::Element>
+    {
+        ArrayFromSlice::from_slice_prefix(slice)
+    }
+
+    /// Parses an array from text whose elements are separated by `separator`,
+    /// trimming whitespace around each element.
+    ///
+    /// Type: `(&str, char) -> Result<[T; N], ParseArrayError<T::Err>>`, where `T: FromStr`
+    ///
+    /// A whitespace separator matches any run of whitespace, so
+    /// `"1.0  0.0\t0.0"` has three elements.  Blank text has no elements.
+    /// This doesn't allocate.
+    ///
+    /// ```rust
+    /// use arraytools::{ArrayTools, ParseArrayError};
+    ///
+    /// assert_eq!(<[u8; 3]>::parse_delimited("255, 128,0", ','), Ok([255, 128, 0]));
+    /// assert_eq!(<[f32; 3]>::parse_delimited(" 1.0 0.0  0.5 ", ' '), Ok([1.0, 0.0, 0.5]));
+    ///
+    /// match <[u8; 3]>::parse_delimited("1,300,3", ',') {
+    ///     Err(ParseArrayError::Element { index, .. }) => assert_eq!(index, 1),
+    ///     _ => unreachable!(),
+    /// }
+    /// assert_eq!(
+    ///     <[u8; 3]>::parse_delimited("1,2", ','),
+    ///     Err(ParseArrayError::Count { expected: 3, found: 2 }),
+    /// );
+    /// ```
+    fn parse_delimited(text: &str, separator: char) -> Result<Self, ParseArrayError<<Self::Element as FromStr>::Err>>
+        where Self: ArrayParse<Self::Element>, Self::Element: FromStr
+    {
+        ArrayParse::parse_delimited(text, separator)
+    }
+
     /// Builds the array `[0, 1, 2, ..., LEN-1]`.
     ///
     /// Type: `() -> [usize; N]`
     ///
+    /// For other numeric types, use [`numeric_indices`](#method.numeric_indices).
+    ///
     /// ```rust
     /// use arraytools::ArrayTools;
     ///
@@ -234,6 +488,66 @@ pub trait ArrayTools: Sized + Sealed {
         ArrayIndices::indices()
     }
 
+    /// Builds the array `[0, 1, 2, ..., LEN-1]` of any numeric type.
+    ///
+    /// Type: `() -> [T; N]`, where `T: Numeric`
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// assert_eq!(<[u8; 3]>::numeric_indices(), [0, 1, 2]);
+    /// assert_eq!(<[f32; 3]>::numeric_indices(), [0.0, 1.0, 2.0]);
+    /// ```
+    fn numeric_indices() -> Self
+        where Self: ArrayProgression<Self::Element>, Self::Element: Numeric
+    {
+        ArrayProgression::numeric_indices()
+    }
+
+    /// Builds the arithmetic progression `[start, start + step, start + 2*step, ...]`.
+    ///
+    /// Type: `(T, T) -> [T; N]`, where `T: Numeric`
+    ///
+    /// Element `i` is computed as `start + step * i`, rather than by adding
+    /// `step` over and over, so rounding errors don't build up with floats.
+    ///
+    /// As `step` has the same type as the elements, unsigned types can only
+    /// count up.  With integers, overflow in `step * i` or in the sum panics
+    /// in debug builds, like any other arithmetic.
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// assert_eq!(<[i32; 4]>::range_from(10, -3), [10, 7, 4, 1]);
+    /// assert_eq!(<[f64; 3]>::range_from(0.5, 0.25), [0.5, 0.75, 1.0]);
+    /// assert_eq!(<[i8; 3]>::range_from(100, -50), [100, 50, 0]);
+    /// ```
+    fn range_from(start: Self::Element, step: Self::Element) -> Self
+        where Self: ArrayProgression<Self::Element>, Self::Element: Numeric
+    {
+        ArrayProgression::range_from(start, step)
+    }
+
+    /// Builds an array of evenly-spaced values from `start` to `end`,
+    /// including both.
+    ///
+    /// Type: `(T, T) -> [T; N]`, where `T: Float`
+    ///
+    /// The ends are exactly `start` and `end`; a one-element array is `[start]`.
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// assert_eq!(<[f32; 5]>::linspace(0.0, 1.0), [0.0, 0.25, 0.5, 0.75, 1.0]);
+    /// assert_eq!(<[f64; 3]>::linspace(1.0, -1.0), [1.0, 0.0, -1.0]);
+    /// assert_eq!(<[f64; 1]>::linspace(2.0, 3.0), [2.0]);
+    /// ```
+    fn linspace(start: Self::Element, end: Self::Element) -> Self
+        where Self: ArrayProgression<Self::Element>, Self::Element: Float
+    {
+        ArrayProgression::linspace(start, end)
+    }
+
     /// Builds a new array by applying the provided function to each element of this array.
     ///
     /// Type: `([T; N], F) -> [U; N]`
@@ -271,170 +585,1161 @@ pub trait ArrayTools: Sized + Sealed {
         ArrayMap::map(self, f);
     }
 
-    /// Combines two equal-length arrays into an array of tuples.
+    /// Builds a new array by converting each element of this array with `Into`.
     ///
-    /// Type: `([T; N], [U; N]) -> [(T, U); N]`
+    /// Type: `[T; N] -> [U; N]`, where `T: Into<U>`
     ///
     /// ```rust
     /// use arraytools::ArrayTools;
     ///
-    /// assert_eq!([10, 20, 30].zip([1.0, 2.0, 3.0]), [(10, 1.0), (20, 2.0), (30, 3.0)]);
+    /// assert_eq!([1_u8, 2, 3].map_into::<u32>(), [1_u32, 2, 3]);
+    ///
+    /// let tuple = <[u8; 2]>::from_tuple((4, 5)).map_into::<f64>().into_tuple();
+    /// assert_eq!(tuple, (4.0, 5.0));
     /// ```
-    fn zip<T>(self, other: T) -> <Self as ArrayZip<T>>::Output
-        where Self: ArrayZip<T>
+    #[must_use = "this returns the new array; it doesn't update the existing one"]
+    fn map_into<U>(self) -> <Self as ArrayMapInto<U>>::Output
+        where Self: ArrayMapInto<U>
     {
-        ArrayZip::zip(self, other)
+        ArrayMapInto::map_into(self)
     }
 
-    /// Combines two equal-length arrays using the provided function.
+    /// Builds a new array by converting each element of this array with `TryInto`,
+    /// returning the first error encountered.
     ///
-    /// Type: `([T; N], [U; N], F) -> [V; N]`
-    /// - when `N <= 1` this requires `F: FnOnce(T, U) -> V`
-    /// - when `N > 1` this requires `F: FnMut(T, U) -> V`
+    /// Type: `[T; N] -> Result<[U; N], <T as TryInto<U>>::Error>`
     ///
     /// ```rust
     /// use arraytools::ArrayTools;
     ///
-    /// assert_eq!([10, 20, 30].zip_with([3, 2, 1], std::ops::Add::add), [13, 22, 31]);
+    /// assert_eq!([1_i32, 2, 3].try_map_into::<u8>(), Ok([1_u8, 2, 3]));
+    /// assert!([1_i32, -2, 300].try_map_into::<u8>().is_err());
     /// ```
-    fn zip_with<T, F>(self, other: T, f: F) -> <Self as ArrayZipWith<T, F>>::Output
-        where Self: ArrayZipWith<T, F>
+    fn try_map_into<U>(self) -> Result<<Self as ArrayTryMapInto<U>>::Output, <Self as ArrayTryMapInto<U>>::Error>
+        where Self: ArrayTryMapInto<U>
     {
-        ArrayZipWith::zip_with(self, other, f)
+        ArrayTryMapInto::try_map_into(self)
     }
 
-    /// Builds an array of references to the elements of this array.
+    /// Returns the first element of this array that satisfies the predicate,
+    /// dropping all the others.
     ///
-    /// Type: `&'a [T; N] -> [&'a T; N]`
+    /// Type: `([T; N], P) -> Option<T>`
+    /// - when `N <= 1` this requires `P: FnOnce(&T) -> bool`
+    /// - when `N > 1` this requires `P: FnMut(&T) -> bool`
+    ///
+    /// Unlike searching with a slice iterator, this moves the element out,
+    /// so it doesn't need `Clone`.  To search without moving, call it on
+    /// [`.as_ref_array()`](#method.as_ref_array) instead.
     ///
     /// ```rust
     /// use arraytools::ArrayTools;
     ///
-    /// let array = &[1, 2, 3, 4, 5];
-    /// assert_eq!(array.as_ref_array(), [&1, &2, &3, &4, &5]);
+    /// let words = ["apple".to_string(), "banana".to_string(), "cherry".to_string()];
+    /// assert_eq!(words.as_ref_array().find(|w| w.contains('n')), Some(&words[1]));
+    /// assert_eq!(words.find(|w| w.starts_with('c')), Some("cherry".to_string()));
     /// ```
-    fn as_ref_array<'a>(&'a self) -> <Self as ArrayAsRef<'a>>::Output
-        where Self: ArrayAsRef<'a>
+    fn find<P>(self, predicate: P) -> Option<Self::Element>
+        where Self: ArrayFind<Self::Element, P>
     {
-        ArrayAsRef::as_ref(self)
+        ArrayFind::find(self, predicate)
     }
 
-    /// Builds an array of mutable references to the elements of this array.
+    /// Returns the index of the first element of this array that satisfies
+    /// the predicate.
     ///
-    /// Type: `&'a mut [T; N] -> [&'a mut T; N]`
+    /// Type: `(&[T; N], impl FnMut(&T) -> bool) -> Option<usize>`
     ///
     /// ```rust
     /// use arraytools::ArrayTools;
     ///
-    /// let array = &mut [1, 2, 3];
-    /// assert_eq!(array.as_ref_array(), [&mut 1, &mut 2, &mut 3]);
+    /// assert_eq!([1, 3, 4, 6].position(|&x| x % 2 == 0), Some(2));
+    /// assert_eq!([1, 3, 5].as_ref_array().position(|&&x| x % 2 == 0), None);
     /// ```
-    fn as_mut_array<'a>(&'a mut self) -> <Self as ArrayAsMut<'a>>::Output
-        where Self: ArrayAsMut<'a>
+    fn position<P>(&self, predicate: P) -> Option<usize>
+        where P: FnMut(&Self::Element) -> bool
     {
-        ArrayAsMut::as_mut(self)
+        self.as_slice().iter().position(predicate)
     }
 
-    /// Appends an item to this array, returning the new array
+    /// Returns whether any element of this array satisfies the predicate.
     ///
-    /// Type: `([T; N], T) -> [T; N+1]`
+    /// Type: `(&[T; N], impl FnMut(&T) -> bool) -> bool`
     ///
     /// ```rust
     /// use arraytools::ArrayTools;
     ///
-    /// assert_eq!([1, 2].push_back(10), [1, 2, 10]);
+    /// assert!([1, 2, 3].any(|&x| x > 2));
+    /// assert!(![1, 2, 3].any(|&x| x > 3));
     /// ```
-    #[must_use = "this returns the new array; it doesn't update the existing one"]
-    fn push_back<U>(self, item: U) -> <Self as ArrayPush<U>>::Output
-        where Self: ArrayPush<U>
+    fn any<P>(&self, predicate: P) -> bool
+        where P: FnMut(&Self::Element) -> bool
     {
-        ArrayPush::push_back(self, item)
+        self.as_slice().iter().any(predicate)
     }
 
-    /// Prepends an item to this array, returning the new array
+    /// Returns whether every element of this array satisfies the predicate.
     ///
-    /// Type: `([T; N], T) -> [T; N+1]`
+    /// Type: `(&[T; N], impl FnMut(&T) -> bool) -> bool`
     ///
     /// ```rust
     /// use arraytools::ArrayTools;
     ///
-    /// assert_eq!([1, 2].push_front(10), [10, 1, 2]);
+    /// assert!([1, 2, 3].all(|&x| x > 0));
+    /// assert!(![1, 2, 3].all(|&x| x > 1));
     /// ```
-    #[must_use = "this returns the new array; it doesn't update the existing one"]
-    fn push_front<U>(self, item: U) -> <Self as ArrayPush<U>>::Output
-        where Self: ArrayPush<U>
+    fn all<P>(&self, predicate: P) -> bool
+        where P: FnMut(&Self::Element) -> bool
     {
-        ArrayPush::push_front(self, item)
+        self.as_slice().iter().all(predicate)
     }
 
-    /// Splits the last item off from this array, returning a tuple of
-    /// an array of the other elements and the split-off item.
+    /// Returns the number of elements of this array that satisfy the predicate.
     ///
-    /// Type: `[T; N+1] -> ([T; N], T)`
+    /// Type: `(&[T; N], impl FnMut(&T) -> bool) -> usize`
     ///
     /// ```rust
     /// use arraytools::ArrayTools;
     ///
-    /// assert_eq!([1, 2, 3].pop_back(), ([1, 2], 3));
+    /// assert_eq!([1, 2, 3, 4, 5].count_where(|&x| x % 2 == 1), 3);
     /// ```
-    #[must_use = "this returns the new array; it doesn't update the existing one"]
-    fn pop_back<U>(self) -> (<Self as ArrayPop<U>>::Output, U)
-        where Self: ArrayPop<U>
+    fn count_where<P>(&self, mut predicate: P) -> usize
+        where P: FnMut(&Self::Element) -> bool
     {
-        ArrayPop::pop_back(self)
+        self.as_slice().iter().filter(|x| predicate(x)).count()
     }
 
-    /// Splits the first item off from this array, returning a tuple of
-    /// an array of the other elements and the split-off item.
+    /// Returns the index of the smallest element of this non-empty array,
+    /// picking the first one if there are several.
     ///
-    /// Type: `[T; N+1] -> ([T; N], T)`
+    /// Type: `&[T; N] -> usize`, where `N >= 1` and `T: Ord`
     ///
     /// ```rust
     /// use arraytools::ArrayTools;
     ///
-    /// assert_eq!([1, 2, 3].pop_front(), ([2, 3], 1));
+    /// assert_eq!([3, 1, 4, 1, 5].argmin(), 1);
     /// ```
-    #[must_use = "this returns the new array; it doesn't update the existing one"]
-    fn pop_front<U>(self) -> (<Self as ArrayPop<U>>::Output, U)
-        where Self: ArrayPop<U>
+    fn argmin(&self) -> usize
+        where Self: ArrayMinMax<Self::Element>, Self::Element: Ord
     {
-        ArrayPop::pop_front(self)
+        self.argmin_by(Ord::cmp)
     }
-}
-
-mod traits {
-    pub trait Sealed {}
 
-    pub trait ArrayGenerate<F> {
-        fn generate(f: F) -> Self;
+    /// Returns the index of the largest element of this non-empty array,
+    /// picking the first one if there are several.
+    ///
+    /// Type: `&[T; N] -> usize`, where `N >= 1` and `T: Ord`
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// assert_eq!([3, 9, 4, 9, 5].argmax(), 1);
+    /// ```
+    fn argmax(&self) -> usize
+        where Self: ArrayMinMax<Self::Element>, Self::Element: Ord
+    {
+        self.argmax_by(Ord::cmp)
     }
 
-    pub trait ArrayRepeat<T> {
-        fn repeat(x: T) -> Self;
+    /// Returns the index of the smallest element of this non-empty array
+    /// according to the provided comparison, picking the first one if there are several.
+    ///
+    /// Type: `(&[T; N], impl FnMut(&T, &T) -> Ordering) -> usize`, where `N >= 1`
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// assert_eq!([2.5, -1.0, 7.0].argmin_by(f64::total_cmp), 1);
+    /// ```
+    fn argmin_by<F>(&self, mut compare: F) -> usize
+        where Self: ArrayMinMax<Self::Element>, F: FnMut(&Self::Element, &Self::Element) -> Ordering
+    {
+        let slice = self.as_slice();
+        (1..slice.len()).fold(0, |best, i| {
+            if compare(&slice[i], &slice[best]) == Ordering::Less { i } else { best }
+        })
     }
 
-    pub trait ArrayFromIter<I> {
-        fn from_iter(it: I) -> Option<Self> where Self: Sized;
+    /// Returns the index of the largest element of this non-empty array
+    /// according to the provided comparison, picking the first one if there are several.
+    ///
+    /// Type: `(&[T; N], impl FnMut(&T, &T) -> Ordering) -> usize`, where `N >= 1`
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// assert_eq!([2.5, -1.0, 7.0].argmax_by(f64::total_cmp), 2);
+    /// ```
+    fn argmax_by<F>(&self, mut compare: F) -> usize
+        where Self: ArrayMinMax<Self::Element>, F: FnMut(&Self::Element, &Self::Element) -> Ordering
+    {
+        let slice = self.as_slice();
+        (1..slice.len()).fold(0, |best, i| {
+            if compare(&slice[i], &slice[best]) == Ordering::Greater { i } else { best }
+        })
     }
 
-    pub trait ArrayIndices {
-        fn indices() -> Self;
+    /// Moves out the element of this non-empty array with the smallest key,
+    /// along with its index, dropping all the others.
+    ///
+    /// Type: `([T; N], impl FnMut(&T) -> K) -> (usize, T)`, where `N >= 1` and `K: Ord`
+    ///
+    /// If several elements are equally small, the first one is returned.
+    /// It calls `f` only once per element.
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// let words = ["kiwi".to_string(), "fig".to_string(), "pear".to_string()];
+    /// let mut calls = 0;
+    /// let shortest = words.into_min_by_key(|w| { calls += 1; w.len() });
+    /// assert_eq!(shortest, (1, "fig".to_string()));
+    /// assert_eq!(calls, 3);
+    /// ```
+    fn into_min_by_key<K, F>(self, f: F) -> (usize, Self::Element)
+        where Self: ArrayMinMax<Self::Element>, K: Ord, F: FnMut(&Self::Element) -> K
+    {
+        ArrayMinMax::into_min_by_key(self, f)
     }
 
-    pub trait ArrayMap<F> {
-        type Output;
-        type OutputElement;
-        fn map(array: Self, f: F) -> Self::Output;
+    /// Moves out the element of this non-empty array with the largest key,
+    /// along with its index, dropping all the others.
+    ///
+    /// Type: `([T; N], impl FnMut(&T) -> K) -> (usize, T)`, where `N >= 1` and `K: Ord`
+    ///
+    /// If several elements are equally large, the first one is returned.
+    /// It calls `f` only once per element.
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// let words = ["kiwi".to_string(), "fig".to_string(), "pear".to_string()];
+    /// assert_eq!(words.into_max_by_key(|w| w.len()), (0, "kiwi".to_string()));
+    /// ```
+    fn into_max_by_key<K, F>(self, f: F) -> (usize, Self::Element)
+        where Self: ArrayMinMax<Self::Element>, K: Ord, F: FnMut(&Self::Element) -> K
+    {
+        ArrayMinMax::into_max_by_key(self, f)
     }
 
-    pub trait ArrayZip<T> {
-        type Output;
-        fn zip(array: Self, other: T) -> Self::Output;
+    /// Moves out the smallest element of this non-empty array according to
+    /// the provided comparison, along with its index, dropping all the others.
+    ///
+    /// Type: `([T; N], impl FnMut(&T, &T) -> Ordering) -> (usize, T)`, where `N >= 1`
+    ///
+    /// If several elements are equally small, the first one is returned.
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// let costs = [(0.5_f64, "walk"), (0.25, "bike"), (3.0, "taxi")];
+    /// assert_eq!(costs.into_min_by(|a, b| a.0.total_cmp(&b.0)), (1, (0.25, "bike")));
+    /// ```
+    fn into_min_by<F>(self, compare: F) -> (usize, Self::Element)
+        where Self: ArrayMinMax<Self::Element>, F: FnMut(&Self::Element, &Self::Element) -> Ordering
+    {
+        ArrayMinMax::into_min_by(self, compare)
     }
 
-    pub trait ArrayZipWith<T, F> {
-        type Output;
-        fn zip_with(array: Self, other: T, f: F) -> Self::Output;
+    /// Moves out the largest element of this non-empty array according to
+    /// the provided comparison, along with its index, dropping all the others.
+    ///
+    /// Type: `([T; N], impl FnMut(&T, &T) -> Ordering) -> (usize, T)`, where `N >= 1`
+    ///
+    /// If several elements are equally large, the first one is returned.
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// let costs = [(0.5_f64, "walk"), (0.25, "bike"), (3.0, "taxi")];
+    /// assert_eq!(costs.into_max_by(|a, b| a.0.total_cmp(&b.0)), (2, (3.0, "taxi")));
+    /// ```
+    fn into_max_by<F>(self, compare: F) -> (usize, Self::Element)
+        where Self: ArrayMinMax<Self::Element>, F: FnMut(&Self::Element, &Self::Element) -> Ordering
+    {
+        ArrayMinMax::into_max_by(self, compare)
+    }
+
+    /// Combines two equal-length arrays into an array of tuples.
+    ///
+    /// Type: `([T; N], [U; N]) -> [(T, U); N]`
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// assert_eq!([10, 20, 30].zip([1.0, 2.0, 3.0]), [(10, 1.0), (20, 2.0), (30, 3.0)]);
+    /// ```
+    fn zip<T>(self, other: T) -> <Self as ArrayZip<T>>::Output
+        where Self: ArrayZip<T>
+    {
+        ArrayZip::zip(self, other)
+    }
+
+    /// Combines two equal-length arrays using the provided function.
+    ///
+    /// Type: `([T; N], [U; N], F) -> [V; N]`
+    /// - when `N <= 1` this requires `F: FnOnce(T, U) -> V`
+    /// - when `N > 1` this requires `F: FnMut(T, U) -> V`
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// assert_eq!([10, 20, 30].zip_with([3, 2, 1], std::ops::Add::add), [13, 22, 31]);
+    /// ```
+    fn zip_with<T, F>(self, other: T, f: F) -> <Self as ArrayZipWith<T, F>>::Output
+        where Self: ArrayZipWith<T, F>
+    {
+        ArrayZipWith::zip_with(self, other, f)
+    }
+
+    /// Pairs every element of this array with every element of another,
+    /// giving a grid with a row for each element of this array.
+    ///
+    /// Type: `([T; N], [U; M]) -> [[(T, U); M]; N]`, where `T: Clone, U: Clone`
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// assert_eq!(
+    ///     [1, 2].cartesian_product(['a', 'b', 'c']),
+    ///     [[(1, 'a'), (1, 'b'), (1, 'c')], [(2, 'a'), (2, 'b'), (2, 'c')]],
+    /// );
+    /// ```
+    fn cartesian_product<U, const M: usize>(self, other: [U; M]) -> <Self as ArrayOuter<Self::Element, U, M>>::Output<(Self::Element, U)>
+        where Self: ArrayOuter<Self::Element, U, M>, Self::Element: Clone, U: Clone
+    {
+        ArrayOuter::outer_with(self, other, |t, u| (t, u))
+    }
+
+    /// Combines every element of this array with every element of another
+    /// using the provided function, giving a grid with a row for each
+    /// element of this array.
+    ///
+    /// Type: `([T; N], [U; M], F) -> [[V; M]; N]`, where `T: Clone, U: Clone, F: FnMut(T, U) -> V`
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// let times_table = [1, 2, 3].outer_with([1, 2, 3, 4], |a, b| a * b);
+    /// assert_eq!(times_table, [[1, 2, 3, 4], [2, 4, 6, 8], [3, 6, 9, 12]]);
+    /// ```
+    fn outer_with<U, V, F, const M: usize>(self, other: [U; M], f: F) -> <Self as ArrayOuter<Self::Element, U, M>>::Output<V>
+        where Self: ArrayOuter<Self::Element, U, M>, Self::Element: Clone, U: Clone, F: FnMut(Self::Element, U) -> V
+    {
+        ArrayOuter::outer_with(self, other, f)
+    }
+
+    /// Returns an adaptor which displays the elements of this array
+    /// separated by `separator`.
+    ///
+    /// Type: `(&'a [T; N], &'a str) -> impl Display + 'a`, where `T: Display`
+    ///
+    /// The [`DisplayWith`] adaptor can also add a prefix and suffix, use a
+    /// custom format for each element, or show only the ends of long arrays.
+    /// It doesn't allocate, so it works in `no_std`.
+    ///
+    /// [`DisplayWith`]: struct.DisplayWith.html
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// assert_eq!([1, 2, 3].display_with(", ").to_string(), "1, 2, 3");
+    ///
+    /// let names = ["Ana".to_string(), "Bo".to_string()];
+    /// let refs = names.as_ref_array();
+    /// assert_eq!(format!("<{:>4}>", refs.display_with("|")), "< Ana|  Bo>");
+    /// ```
+    fn display_with<'a>(&'a self, separator: &'a str) -> DisplayWith<'a, Self::Element> {
+        DisplayWith::new(self.as_slice(), separator)
+    }
+
+    /// Shuffles the elements of this array in place, using the provided
+    /// source of randomness.
+    ///
+    /// Type: `(&mut [T; N], &mut R)`, where `R: RandomSource`
+    ///
+    /// This is a Fisher-Yates shuffle, so every ordering is equally likely
+    /// (given a good [`RandomSource`]), and it doesn't allocate.
+    ///
+    /// [`RandomSource`]: trait.RandomSource.html
+    ///
+    /// ```rust
+    /// use arraytools::{ArrayTools, RandomSource};
+    /// # struct Counter(u64);
+    /// # impl RandomSource for Counter {
+    /// #     fn next_u32(&mut self) -> u32 { self.next_u64() as u32 }
+    /// #     fn next_u64(&mut self) -> u64 { self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15); self.0 }
+    /// # }
+    /// # let mut rng = Counter(1);
+    ///
+    /// let mut array = [1, 2, 3, 4, 5];
+    /// array.shuffle(&mut rng);
+    /// array.sort();
+    /// assert_eq!(array, [1, 2, 3, 4, 5]);
+    /// ```
+    fn shuffle(&mut self, rng: &mut (impl RandomSource + ?Sized)) {
+        random::partial_shuffle(self.as_mut_slice(), Self::LEN, rng)
+    }
+
+    /// Returns this array with its elements shuffled, using the provided
+    /// source of randomness.
+    ///
+    /// Type: `([T; N], &mut R) -> [T; N]`, where `R: RandomSource`
+    ///
+    /// This is the by-value form of [`.shuffle()`](#method.shuffle).
+    ///
+    /// ```rust
+    /// use arraytools::{ArrayTools, RandomSource};
+    /// # struct Counter(u64);
+    /// # impl RandomSource for Counter {
+    /// #     fn next_u32(&mut self) -> u32 { self.next_u64() as u32 }
+    /// #     fn next_u64(&mut self) -> u64 { self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15); self.0 }
+    /// # }
+    /// # let mut rng = Counter(1);
+    ///
+    /// let names = ["Ana", "Bo", "Cy"].shuffled(&mut rng);
+    /// assert!(names.contains(&"Bo"));
+    /// ```
+    #[must_use = "this returns the shuffled array; use `.shuffle()` to shuffle in place"]
+    fn shuffled(mut self, rng: &mut (impl RandomSource + ?Sized)) -> Self {
+        self.shuffle(rng);
+        self
+    }
+
+    /// Picks `K` distinct elements of this array at random, using the
+    /// provided source of randomness.
+    ///
+    /// Type: `([T; N], &mut R) -> [T; K]`, where `R: RandomSource`
+    ///
+    /// Every selection is equally likely, and so is every order of the
+    /// elements in it.  `K` must be at most `N`, which is checked at compile time.
+    ///
+    /// ```rust
+    /// use arraytools::{ArrayTools, RandomSource};
+    /// # struct Counter(u64);
+    /// # impl RandomSource for Counter {
+    /// #     fn next_u32(&mut self) -> u32 { self.next_u64() as u32 }
+    /// #     fn next_u64(&mut self) -> u64 { self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15); self.0 }
+    /// # }
+    /// # let mut rng = Counter(1);
+    ///
+    /// let [a, b, c] = [10, 20, 30, 40, 50].choose_multiple::<3>(&mut rng);
+    /// assert!(a != b && b != c && a != c);
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// use arraytools::{ArrayTools, RandomSource};
+    /// # struct Zero;
+    /// # impl RandomSource for Zero {
+    /// #     fn next_u32(&mut self) -> u32 { 0 }
+    /// #     fn next_u64(&mut self) -> u64 { 0 }
+    /// # }
+    ///
+    /// [1, 2].choose_multiple::<3>(&mut Zero); // can't choose 3 of 2
+    /// ```
+    fn choose_multiple<const K: usize>(mut self, rng: &mut (impl RandomSource + ?Sized)) -> [Self::Element; K]
+        where Self: IntoIterator<Item = Self::Element>
+    {
+        const { assert!(K <= Self::LEN, "cannot choose more elements than the array has") };
+        random::partial_shuffle(self.as_mut_slice(), K, rng);
+        match iter::fill(&mut IntoIterator::into_iter(self)) {
+            Ok(chosen) => chosen,
+            Err(_) => unreachable!("the array has at least K elements"),
+        }
+    }
+
+    /// Returns an iterator over every way of choosing `K` of this array's
+    /// elements, keeping them in their original order.
+    ///
+    /// Type: `[T; N] -> impl Iterator<Item = [T; K]>`, where `T: Clone`
+    ///
+    /// The combinations come in lexicographic order of the chosen positions.
+    /// If `K > N` there are none.  To avoid the clones, combine references
+    /// from [`.as_ref_array()`](#method.as_ref_array) instead.
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// let mut pairs = [1, 2, 3].combinations::<2>();
+    /// assert_eq!(pairs.next(), Some([1, 2]));
+    /// assert_eq!(pairs.next(), Some([1, 3]));
+    /// assert_eq!(pairs.next(), Some([2, 3]));
+    /// assert_eq!(pairs.next(), None);
+    ///
+    /// let words = ["ant".to_string(), "bee".to_string(), "cat".to_string(), "dog".to_string()];
+    /// assert_eq!(words.as_ref_array().combinations::<3>().count(), 4);
+    /// ```
+    fn combinations<const K: usize>(self) -> <Self as ArrayCombinations<K>>::Output
+        where Self: ArrayCombinations<K>
+    {
+        ArrayCombinations::combinations(self)
+    }
+
+    /// Returns an iterator over every ordering of this array's elements.
+    ///
+    /// Type: `[T; N] -> impl Iterator<Item = [T; N]>`, where `T: Clone`
+    ///
+    /// This uses Heap's algorithm, so each permutation differs from the
+    /// previous one by swapping a single pair, starting with the array as it is.
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// let mut orders = [1, 2, 3].permutations();
+    /// assert_eq!(orders.next(), Some([1, 2, 3]));
+    /// assert_eq!(orders.next(), Some([2, 1, 3]));
+    /// assert_eq!(orders.count(), 4);
+    ///
+    /// let best = [3, 1, 2].permutations().max_by_key(|p| p[0] * 100 + p[1] * 10 + p[2]);
+    /// assert_eq!(best, Some([3, 2, 1]));
+    /// ```
+    fn permutations(self) -> <Self as ArrayPermutations>::Output
+        where Self: ArrayPermutations
+    {
+        ArrayPermutations::permutations(self)
+    }
+
+    /// Compares two equal-length arrays element by element, returning
+    /// whether each element of this one is equal to the other's.
+    ///
+    /// Type: `([T; N], [T; N]) -> [bool; N]`, where `T: PartialEq`
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// assert_eq!([1, 2, 3].eq_mask([1, 0, 3]), [true, false, true]);
+    /// ```
+    fn eq_mask(self, other: Self) -> <Self as ArrayCompare<Self::Element>>::Mask
+        where Self: ArrayCompare<Self::Element>, Self::Element: PartialEq
+    {
+        ArrayCompare::eq_mask(self, other)
+    }
+
+    /// Compares two equal-length arrays element by element, returning
+    /// whether each element of this one is not equal to the other's.
+    ///
+    /// Type: `([T; N], [T; N]) -> [bool; N]`, where `T: PartialEq`
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// assert_eq!([1, 2, 3].ne_mask([1, 0, 3]), [false, true, false]);
+    /// ```
+    fn ne_mask(self, other: Self) -> <Self as ArrayCompare<Self::Element>>::Mask
+        where Self: ArrayCompare<Self::Element>, Self::Element: PartialEq
+    {
+        ArrayCompare::ne_mask(self, other)
+    }
+
+    /// Compares two equal-length arrays element by element, returning
+    /// whether each element of this one is less than the other's.
+    ///
+    /// Type: `([T; N], [T; N]) -> [bool; N]`, where `T: PartialOrd`
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// assert_eq!([1, 2, 3].lt_mask([2, 2, 2]), [true, false, false]);
+    /// ```
+    fn lt_mask(self, other: Self) -> <Self as ArrayCompare<Self::Element>>::Mask
+        where Self: ArrayCompare<Self::Element>, Self::Element: PartialOrd
+    {
+        ArrayCompare::lt_mask(self, other)
+    }
+
+    /// Compares two equal-length arrays element by element, returning
+    /// whether each element of this one is less than or equal to the other's.
+    ///
+    /// Type: `([T; N], [T; N]) -> [bool; N]`, where `T: PartialOrd`
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// assert_eq!([1, 2, 3].le_mask([2, 2, 2]), [true, true, false]);
+    /// ```
+    fn le_mask(self, other: Self) -> <Self as ArrayCompare<Self::Element>>::Mask
+        where Self: ArrayCompare<Self::Element>, Self::Element: PartialOrd
+    {
+        ArrayCompare::le_mask(self, other)
+    }
+
+    /// Compares two equal-length arrays element by element, returning
+    /// whether each element of this one is greater than the other's.
+    ///
+    /// Type: `([T; N], [T; N]) -> [bool; N]`, where `T: PartialOrd`
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// assert_eq!([1, 2, 3].gt_mask([2, 2, 2]), [false, false, true]);
+    /// ```
+    fn gt_mask(self, other: Self) -> <Self as ArrayCompare<Self::Element>>::Mask
+        where Self: ArrayCompare<Self::Element>, Self::Element: PartialOrd
+    {
+        ArrayCompare::gt_mask(self, other)
+    }
+
+    /// Compares two equal-length arrays element by element, returning
+    /// whether each element of this one is greater than or equal to the other's.
+    ///
+    /// Type: `([T; N], [T; N]) -> [bool; N]`, where `T: PartialOrd`
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// assert_eq!([1, 2, 3].ge_mask([2, 2, 2]), [false, true, true]);
+    /// ```
+    fn ge_mask(self, other: Self) -> <Self as ArrayCompare<Self::Element>>::Mask
+        where Self: ArrayCompare<Self::Element>, Self::Element: PartialOrd
+    {
+        ArrayCompare::ge_mask(self, other)
+    }
+
+    /// Builds an array of references to the elements of this array.
+    ///
+    /// Type: `&'a [T; N] -> [&'a T; N]`
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// let array = &[1, 2, 3, 4, 5];
+    /// assert_eq!(array.as_ref_array(), [&1, &2, &3, &4, &5]);
+    /// ```
+    fn as_ref_array<'a>(&'a self) -> <Self as ArrayAsRef<'a>>::Output
+        where Self: ArrayAsRef<'a>
+    {
+        ArrayAsRef::as_ref(self)
+    }
+
+    /// Builds an array of mutable references to the elements of this array.
+    ///
+    /// Type: `&'a mut [T; N] -> [&'a mut T; N]`
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// let array = &mut [1, 2, 3];
+    /// assert_eq!(array.as_ref_array(), [&mut 1, &mut 2, &mut 3]);
+    /// ```
+    fn as_mut_array<'a>(&'a mut self) -> <Self as ArrayAsMut<'a>>::Output
+        where Self: ArrayAsMut<'a>
+    {
+        ArrayAsMut::as_mut(self)
+    }
+
+    /// The homogeneous tuple of references equivalent to a reference to this array type.
+    ///
+    /// ```rust
+    /// # type T = usize;
+    /// use arraytools::ArrayTools;
+    ///
+    /// # fn _foo<'a>() where
+    /// [T; 3]: ArrayTools<RefTuple<'a> = (&'a T, &'a T, &'a T)>
+    /// # {}
+    /// ```
+    type RefTuple<'a> where Self: 'a;
+
+    /// The homogeneous tuple of mutable references equivalent to a mutable
+    /// reference to this array type.
+    ///
+    /// ```rust
+    /// # type T = usize;
+    /// use arraytools::ArrayTools;
+    ///
+    /// # fn _foo<'a>() where
+    /// [T; 2]: ArrayTools<MutTuple<'a> = (&'a mut T, &'a mut T)>
+    /// # {}
+    /// ```
+    type MutTuple<'a> where Self: 'a;
+
+    /// Builds a homogeneous tuple of references to the elements of this array.
+    ///
+    /// Type: `&'a [T; N] -> (&'a T, &'a T, ..., &'a T)`
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// let names = ["Ada".to_string(), "Grace".to_string()];
+    /// let (first, second) = names.as_ref_tuple();
+    /// assert_eq!((first.len(), second.len()), (3, 5));
+    /// ```
+    fn as_ref_tuple(&self) -> Self::RefTuple<'_>;
+
+    /// Builds a homogeneous tuple of mutable references to the elements of this array.
+    ///
+    /// Type: `&'a mut [T; N] -> (&'a mut T, &'a mut T, ..., &'a mut T)`
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// let mut position = [1.0, 2.0, 3.0];
+    /// let (x, _, z) = position.as_mut_tuple();
+    /// std::mem::swap(x, z);
+    /// assert_eq!(position, [3.0, 2.0, 1.0]);
+    /// ```
+    fn as_mut_tuple(&mut self) -> Self::MutTuple<'_>;
+
+    /// Returns a reference to the element at index `I`, checking at compile time
+    /// that `I` is in bounds.
+    ///
+    /// Type: `&'a [T; N] -> &'a T`, where `I < N`
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// assert_eq!([1, 2, 3].get_nth::<2>(), &3);
+    /// ```
+    ///
+    /// An out-of-bounds index is a compile error rather than a panic:
+    ///
+    /// ```compile_fail
+    /// use arraytools::ArrayTools;
+    ///
+    /// [1, 2, 3].get_nth::<3>();
+    /// ```
+    ///
+    /// (This isn't called `get` so that it doesn't hide the slice method of that name.)
+    fn get_nth<const I: usize>(&self) -> &Self::Element {
+        const { assert!(I < Self::LEN, "array index out of bounds") };
+        &self.as_slice()[I]
+    }
+
+    /// Returns a mutable reference to the element at index `I`, checking at
+    /// compile time that `I` is in bounds.
+    ///
+    /// Type: `&'a mut [T; N] -> &'a mut T`, where `I < N`
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// let mut array = [1, 2, 3];
+    /// *array.get_nth_mut::<0>() = 10;
+    /// assert_eq!(array, [10, 2, 3]);
+    /// ```
+    fn get_nth_mut<const I: usize>(&mut self) -> &mut Self::Element {
+        const { assert!(I < Self::LEN, "array index out of bounds") };
+        &mut self.as_mut_slice()[I]
+    }
+
+    /// Moves out the element at index `I`, dropping all the others, and
+    /// checking at compile time that `I` is in bounds.
+    ///
+    /// Type: `[T; N] -> T`, where `I < N`
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// let names = ["a".to_string(), "b".to_string(), "c".to_string()];
+    /// assert_eq!(names.into_nth::<1>(), "b");
+    /// ```
+    fn into_nth<const I: usize>(self) -> Self::Element
+        where Self: ArrayIntoNth<Self::Element>
+    {
+        const { assert!(I < Self::LEN, "array index out of bounds") };
+        ArrayIntoNth::into_nth::<I>(self)
+    }
+
+    /// Replaces the element at index `I`, returning the updated array and the
+    /// old element, and checking at compile time that `I` is in bounds.
+    ///
+    /// Type: `([T; N], T) -> ([T; N], T)`, where `I < N`
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// assert_eq!([1, 2, 3].replace::<1>(20), ([1, 20, 3], 2));
+    /// ```
+    #[must_use = "this returns the new array; it doesn't update the existing one"]
+    fn replace<const I: usize>(mut self, value: Self::Element) -> (Self, Self::Element) {
+        const { assert!(I < Self::LEN, "array index out of bounds") };
+        let old = mem::replace(&mut self.as_mut_slice()[I], value);
+        (self, old)
+    }
+
+    /// Appends an item to this array, returning the new array
+    ///
+    /// Type: `([T; N], T) -> [T; N+1]`
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// assert_eq!([1, 2].push_back(10), [1, 2, 10]);
+    /// ```
+    #[must_use = "this returns the new array; it doesn't update the existing one"]
+    fn push_back<U>(self, item: U) -> <Self as ArrayPush<U>>::Output
+        where Self: ArrayPush<U>
+    {
+        ArrayPush::push_back(self, item)
+    }
+
+    /// Prepends an item to this array, returning the new array
+    ///
+    /// Type: `([T; N], T) -> [T; N+1]`
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// assert_eq!([1, 2].push_front(10), [10, 1, 2]);
+    /// ```
+    #[must_use = "this returns the new array; it doesn't update the existing one"]
+    fn push_front<U>(self, item: U) -> <Self as ArrayPush<U>>::Output
+        where Self: ArrayPush<U>
+    {
+        ArrayPush::push_front(self, item)
+    }
+
+    /// Splits the last item off from this array, returning a tuple of
+    /// an array of the other elements and the split-off item.
+    ///
+    /// Type: `[T; N+1] -> ([T; N], T)`
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// assert_eq!([1, 2, 3].pop_back(), ([1, 2], 3));
+    /// ```
+    #[must_use = "this returns the new array; it doesn't update the existing one"]
+    fn pop_back<U>(self) -> (<Self as ArrayPop<U>>::Output, U)
+        where Self: ArrayPop<U>
+    {
+        ArrayPop::pop_back(self)
+    }
+
+    /// Splits the first item off from this array, returning a tuple of
+    /// an array of the other elements and the split-off item.
+    ///
+    /// Type: `[T; N+1] -> ([T; N], T)`
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// assert_eq!([1, 2, 3].pop_front(), ([2, 3], 1));
+    /// ```
+    #[must_use = "this returns the new array; it doesn't update the existing one"]
+    fn pop_front<U>(self) -> (<Self as ArrayPop<U>>::Output, U)
+        where Self: ArrayPop<U>
+    {
+        ArrayPop::pop_front(self)
+    }
+
+    /// Inserts an item into this array at index `I`, returning the new array,
+    /// and checking at compile time that `I` is in bounds.
+    ///
+    /// Type: `([T; N], T) -> [T; N+1]`, where `I <= N`
+    ///
+    /// This generalizes [`.push_front()`] (which is `insert::<0>`) and
+    /// [`.push_back()`] (which is `insert::<N>`).
+    ///
+    /// [`.push_front()`]: #method.push_front
+    /// [`.push_back()`]: #method.push_back
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// assert_eq!([1, 2, 3].insert::<1>(10), [1, 10, 2, 3]);
+    /// assert_eq!([1, 2, 3].insert::<3>(10), [1, 2, 3, 10]);
+    /// ```
+    #[must_use = "this returns the new array; it doesn't update the existing one"]
+    fn insert<const I: usize>(self, item: Self::Element) -> <Self as ArrayPush<Self::Element>>::Output
+        where Self: ArrayPush<Self::Element>
+    {
+        const { assert!(I <= Self::LEN, "insertion index out of bounds") };
+        ArrayPush::insert::<I>(self, item)
+    }
+
+    /// Removes the item at index `I` from this array, returning a tuple of
+    /// an array of the other elements and the removed item, and checking at
+    /// compile time that `I` is in bounds.
+    ///
+    /// Type: `[T; N+1] -> ([T; N], T)`, where `I <= N`
+    ///
+    /// This generalizes [`.pop_front()`] (which is `remove::<0>`) and
+    /// [`.pop_back()`] (which is `remove::<N>`).
+    ///
+    /// [`.pop_front()`]: #method.pop_front
+    /// [`.pop_back()`]: #method.pop_back
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// assert_eq!([1, 2, 3, 4].remove::<1>(), ([1, 3, 4], 2));
+    /// ```
+    #[must_use = "this returns the new array; it doesn't update the existing one"]
+    fn remove<const I: usize>(self) -> (<Self as ArrayPop<Self::Element>>::Output, Self::Element)
+        where Self: ArrayPop<Self::Element>
+    {
+        const { assert!(I < Self::LEN, "removal index out of bounds") };
+        ArrayPop::remove::<I>(self)
+    }
+
+    /// Converts this array of numbers into the concatenation of their
+    /// little-endian byte representations.
+    ///
+    /// Type: `[T; N] -> [u8; N * size_of::<T>()]`, for primitive integers and floats
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// assert_eq!([0x1234_u16, 0x5678].to_le_bytes(), [0x34, 0x12, 0x78, 0x56]);
+    /// ```
+    fn to_le_bytes(self) -> <Self as ArrayBytes>::Bytes
+        where Self: ArrayBytes
+    {
+        ArrayBytes::to_le_bytes(self)
+    }
+
+    /// Converts this array of numbers into the concatenation of their
+    /// big-endian byte representations.
+    ///
+    /// Type: `[T; N] -> [u8; N * size_of::<T>()]`, for primitive integers and floats
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// assert_eq!([0x1234_u16, 0x5678].to_be_bytes(), [0x12, 0x34, 0x56, 0x78]);
+    /// ```
+    fn to_be_bytes(self) -> <Self as ArrayBytes>::Bytes
+        where Self: ArrayBytes
+    {
+        ArrayBytes::to_be_bytes(self)
+    }
+
+    /// Converts this array of numbers into the concatenation of their
+    /// native-endian byte representations.
+    ///
+    /// Type: `[T; N] -> [u8; N * size_of::<T>()]`, for primitive integers and floats
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// let bytes = [1.0_f32, -2.0].to_ne_bytes();
+    /// assert_eq!(bytes.len(), 8);
+    /// assert_eq!(<[f32; 2]>::from_ne_bytes(bytes), [1.0, -2.0]);
+    /// ```
+    fn to_ne_bytes(self) -> <Self as ArrayBytes>::Bytes
+        where Self: ArrayBytes
+    {
+        ArrayBytes::to_ne_bytes(self)
+    }
+
+    /// Builds an array of numbers from the concatenation of their
+    /// little-endian byte representations.
+    ///
+    /// Type: `[u8; N * size_of::<T>()] -> [T; N]`, for primitive integers and floats
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// let header = [0x01, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF];
+    /// assert_eq!(<[i32; 2]>::from_le_bytes(header), [1, -1]);
+    /// ```
+    fn from_le_bytes(bytes: <Self as ArrayBytes>::Bytes) -> Self
+        where Self: ArrayBytes
+    {
+        ArrayBytes::from_le_bytes(bytes)
+    }
+
+    /// Builds an array of numbers from the concatenation of their
+    /// big-endian byte representations.
+    ///
+    /// Type: `[u8; N * size_of::<T>()] -> [T; N]`, for primitive integers and floats
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// let header = [0x00, 0x50, 0x01, 0xBB];
+    /// assert_eq!(<[u16; 2]>::from_be_bytes(header), [80, 443]);
+    /// ```
+    fn from_be_bytes(bytes: <Self as ArrayBytes>::Bytes) -> Self
+        where Self: ArrayBytes
+    {
+        ArrayBytes::from_be_bytes(bytes)
+    }
+
+    /// Builds an array of numbers from the concatenation of their
+    /// native-endian byte representations.
+    ///
+    /// Type: `[u8; N * size_of::<T>()] -> [T; N]`, for primitive integers and floats
+    ///
+    /// ```rust
+    /// use arraytools::ArrayTools;
+    ///
+    /// let bytes = [7_u64, 11].to_ne_bytes();
+    /// assert_eq!(<[u64; 2]>::from_ne_bytes(bytes), [7, 11]);
+    /// ```
+    fn from_ne_bytes(bytes: <Self as ArrayBytes>::Bytes) -> Self
+        where Self: ArrayBytes
+    {
+        ArrayBytes::from_ne_bytes(bytes)
+    }
+}
+
+/// The error returned when a slice doesn't have the length needed
+/// to build an array, as from [`ArrayTools::from_slice_cloned`].
+///
+/// [`ArrayTools::from_slice_cloned`]: trait.ArrayTools.html#method.from_slice_cloned
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LengthError {
+    expected: usize,
+    actual: usize,
+}
+
+impl LengthError {
+    fn new(expected: usize, actual: usize) -> Self {
+        LengthError { expected, actual }
+    }
+
+    /// The number of elements that were needed.
+    pub fn expected(&self) -> usize {
+        self.expected
+    }
+
+    /// The number of elements that were provided.
+    pub fn actual(&self) -> usize {
+        self.actual
+    }
+}
+
+impl fmt::Display for LengthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "expected {} elements, but found {}", self.expected, self.actual)
+    }
+}
+
+impl core::error::Error for LengthError {}
+
+mod traits {
+    use core::cmp::Ordering;
+    use core::fmt;
+    use core::str::FromStr;
+
+    pub trait Sealed {}
+
+    pub trait ArrayGenerate<F> {
+        fn generate(f: F) -> Self;
+    }
+
+    pub trait ArrayRepeat<T> {
+        fn repeat(x: T) -> Self;
+    }
+
+    pub trait ArrayFromIter<I> {
+        fn from_iter(it: I) -> Option<Self> where Self: Sized;
+    }
+
+    pub trait ArrayFromSlice<T> {
+        fn from_slice_cloned(slice: &[T]) -> Result<Self, super::LengthError> where Self: Sized;
+        fn from_slice_prefix(slice: &[T]) -> Result<Self, super::LengthError> where Self: Sized;
+    }
+
+    pub trait ArrayIndices {
+        fn indices() -> Self;
+    }
+
+    pub trait ArrayProgression<T> {
+        fn numeric_indices() -> Self where T: NumericOps;
+        fn range_from(start: T, step: T) -> Self where T: NumericOps;
+        fn linspace(start: T, end: T) -> Self where T: FloatOps;
+    }
+
+    pub trait NumericOps: Copy + core::ops::Add<Output = Self> + core::ops::Mul<Output = Self> {
+        fn from_index(i: usize) -> Self;
+    }
+
+    pub trait FloatOps: NumericOps {
+        fn linspace_nth(start: Self, end: Self, i: usize, n: usize) -> Self;
+    }
+
+    pub trait ArrayMap<F> {
+        type Output;
+        type OutputElement;
+        fn map(array: Self, f: F) -> Self::Output;
+    }
+
+    pub trait ArrayMapInto<U> {
+        type Output;
+        fn map_into(array: Self) -> Self::Output;
+    }
+
+    pub trait ArrayTryMapInto<U> {
+        type Output;
+        type Error;
+        fn try_map_into(array: Self) -> Result<Self::Output, Self::Error>;
+    }
+
+    pub trait ArrayFind<T, P> {
+        fn find(array: Self, predicate: P) -> Option<T>;
+    }
+
+    pub trait ArrayMinMax<T> {
+        fn into_min_by<F>(array: Self, compare: F) -> (usize, T)
+            where F: FnMut(&T, &T) -> Ordering;
+        fn into_max_by<F>(array: Self, compare: F) -> (usize, T)
+            where F: FnMut(&T, &T) -> Ordering;
+        fn into_min_by_key<K, F>(array: Self, f: F) -> (usize, T)
+            where K: Ord, F: FnMut(&T) -> K;
+        fn into_max_by_key<K, F>(array: Self, f: F) -> (usize, T)
+            where K: Ord, F: FnMut(&T) -> K;
+    }
+
+    pub trait ArrayZip<T> {
+        type Output;
+        fn zip(array: Self, other: T) -> Self::Output;
+    }
+
+    pub trait ArrayZipWith<T, F> {
+        type Output;
+        fn zip_with(array: Self, other: T, f: F) -> Self::Output;
+    }
+
+    pub trait ArrayNested {
+        type Nested;
+        fn into_nested_tuple(array: Self) -> Self::Nested;
+        fn from_nested_tuple(tuple: Self::Nested) -> Self;
+    }
+
+    pub trait ArrayNested3 {
+        type Nested;
+        fn into_nested_tuple3(array: Self) -> Self::Nested;
+        fn from_nested_tuple3(tuple: Self::Nested) -> Self;
+    }
+
+    pub trait ArrayOuter<T, U, const M: usize> {
+        type Output<V>;
+        fn outer_with<V, F>(array: Self, other: [U; M], f: F) -> Self::Output<V>
+            where T: Clone, U: Clone, F: FnMut(T, U) -> V;
+    }
+
+    pub trait ArrayCombinations<const K: usize> {
+        type Output;
+        fn combinations(array: Self) -> Self::Output;
+    }
+
+    pub trait ArrayPermutations {
+        type Output;
+        fn permutations(array: Self) -> Self::Output;
+    }
+
+    pub trait ElementFormat<T> {
+        fn format(&self, item: &T, f: &mut fmt::Formatter) -> fmt::Result;
+    }
+
+    pub trait ArrayParse<T: FromStr> {
+        fn parse_delimited(text: &str, separator: char) -> Result<Self, super::ParseArrayError<T::Err>> where Self: Sized;
+    }
+
+    pub trait ArrayVecStorage {
+        type Slots;
+    }
+
+    pub trait ArrayCompare<T> {
+        type Mask;
+        fn eq_mask(array: Self, other: Self) -> Self::Mask where T: PartialEq;
+        fn ne_mask(array: Self, other: Self) -> Self::Mask where T: PartialEq;
+        fn lt_mask(array: Self, other: Self) -> Self::Mask where T: PartialOrd;
+        fn le_mask(array: Self, other: Self) -> Self::Mask where T: PartialOrd;
+        fn gt_mask(array: Self, other: Self) -> Self::Mask where T: PartialOrd;
+        fn ge_mask(array: Self, other: Self) -> Self::Mask where T: PartialOrd;
     }
 
     pub trait ArrayAsRef<'a> {
@@ -447,36 +1752,94 @@ mod traits {
         fn as_mut(array: &'a mut Self) -> Self::Output;
     }
 
+    pub trait ArrayIntoNth<T> {
+        fn into_nth<const I: usize>(array: Self) -> T;
+    }
+
     pub trait ArrayPush<T> {
         type Output;
         fn push_back(array: Self, item: T) -> Self::Output;
         fn push_front(array: Self, item: T) -> Self::Output;
+        fn insert<const I: usize>(array: Self, item: T) -> Self::Output;
     }
 
     pub trait ArrayPop<T> {
         type Output;
         fn pop_back(array: Self) -> (Self::Output, T);
         fn pop_front(array: Self) -> (Self::Output, T);
+        fn remove<const I: usize>(array: Self) -> (Self::Output, T);
+    }
+
+    pub trait ArrayBits {
+        type Bits: BitsInt;
+    }
+
+    pub trait BitsInt: Copy {
+        const ZERO: Self;
+        fn with_bit(self, i: usize) -> Self;
+        fn bit(self, i: usize) -> bool;
+    }
+
+    pub trait NumberBytes: Sized {
+        type Bytes: AsRef<[u8]> + AsMut<[u8]> + Default;
+        fn to_le_bytes(self) -> Self::Bytes;
+        fn to_be_bytes(self) -> Self::Bytes;
+        fn to_ne_bytes(self) -> Self::Bytes;
+        fn from_le_bytes(bytes: Self::Bytes) -> Self;
+        fn from_be_bytes(bytes: Self::Bytes) -> Self;
+        fn from_ne_bytes(bytes: Self::Bytes) -> Self;
+    }
+
+    pub trait ArrayBytes {
+        type Bytes;
+        fn to_le_bytes(array: Self) -> Self::Bytes;
+        fn to_be_bytes(array: Self) -> Self::Bytes;
+        fn to_ne_bytes(array: Self) -> Self::Bytes;
+        fn from_le_bytes(bytes: Self::Bytes) -> Self;
+        fn from_be_bytes(bytes: Self::Bytes) -> Self;
+        fn from_ne_bytes(bytes: Self::Bytes) -> Self;
     }
 }
 
-#[allow(unused_mut, unused_variables)]
+#[allow(unused_mut, unused_variables, unused_assignments, clippy::unused_unit)]
 mod impls {
     use super::*;
+    use core::convert::TryInto;
 
-    macro_rules! replace_ident {
-        ($i:ident => $($j:tt)*) => ($($j)*)
-    }
 
     macro_rules! array_by_cloning {
         ($x:ident:) => ( [] );
         ($x:ident: $first:ident $($i:ident)*) => ( [$(replace_ident!($i => $x.clone()),)* $x] );
     }
 
+    impl<T, const N: usize> Sealed for [T; N] {}
+
+    macro_rules! impl_tuple_tools {
+        ($n:expr;) => (
+            impl Sealed for () {}
+            impl<T> TupleTools<T> for () {
+                type Array = [T; 0];
+                type RefTuple<'a> = ();
+                fn as_ref_tuple(&self) -> Self::RefTuple<'_> {}
+            }
+        );
+        ($n:expr; $($i:ident)+) => (
+            impl<T> Sealed for ($(replace_ident!($i => T),)*) {}
+            impl<T> TupleTools<T> for ($(replace_ident!($i => T),)*) {
+                type Array = [T; $n];
+                type RefTuple<'a> = ($(replace_ident!($i => &'a T),)*) where T: 'a;
+                fn as_ref_tuple(&self) -> Self::RefTuple<'_> {
+                    let ($($i,)*) = self;
+                    ($($i,)*)
+                }
+            }
+        )
+    }
+
     macro_rules! impl_for_size {
         ($n:expr; $fn_trait:ident => $($i:ident)* / $($j:ident)*) => (
 
-            impl<T> Sealed for [T; $n] {}
+            impl_tuple_tools!($n; $($i)*);
             impl<T> ArrayTools for [T; $n] {
                 type Element = T;
                 const LEN: usize = $n;
@@ -492,6 +1855,17 @@ mod impls {
                     let [$($i,)*] = self;
                     ($($i,)*)
                 }
+
+                type RefTuple<'a> = ($(replace_ident!($i => &'a T),)*) where T: 'a;
+                type MutTuple<'a> = ($(replace_ident!($i => &'a mut T),)*) where T: 'a;
+                fn as_ref_tuple(&self) -> Self::RefTuple<'_> {
+                    let [$($i,)*] = self;
+                    ($($i,)*)
+                }
+                fn as_mut_tuple(&mut self) -> Self::MutTuple<'_> {
+                    let [$($i,)*] = self;
+                    ($($i,)*)
+                }
             }
             impl<T, F> ArrayGenerate<F> for [T; $n]
                 where F: $fn_trait() -> T
@@ -514,12 +1888,40 @@ mod impls {
                     Some([$(replace_ident!($i => it.next()?),)*])
                 }
             }
+            impl<T> ArrayFromSlice<T> for [T; $n]
+                where T: Clone
+            {
+                fn from_slice_cloned(slice: &[T]) -> Result<Self, LengthError> {
+                    if slice.len() != $n {
+                        return Err(LengthError::new($n, slice.len()));
+                    }
+                    <Self as ArrayFromSlice<T>>::from_slice_prefix(slice)
+                }
+                fn from_slice_prefix(slice: &[T]) -> Result<Self, LengthError> {
+                    <Self as ArrayFromIter<_>>::from_iter(slice.iter().cloned())
+                        .ok_or_else(|| LengthError::new($n, slice.len()))
+                }
+            }
             impl ArrayIndices for [usize; $n] {
                 fn indices() -> Self {
                     let mut i = 0;
                     ArrayTools::generate(|| { let t = i; i += 1; t })
                 }
             }
+            impl<T> ArrayProgression<T> for [T; $n] {
+                fn numeric_indices() -> Self where T: NumericOps {
+                    let mut i = 0;
+                    ArrayTools::generate(|| { let t = T::from_index(i); i += 1; t })
+                }
+                fn range_from(start: T, step: T) -> Self where T: NumericOps {
+                    let mut i = 0;
+                    ArrayTools::generate(|| { let t = start + step * T::from_index(i); i += 1; t })
+                }
+                fn linspace(start: T, end: T) -> Self where T: FloatOps {
+                    let mut i = 0;
+                    ArrayTools::generate(|| { let t = T::linspace_nth(start, end, i, $n); i += 1; t })
+                }
+            }
             impl<T, U, F> ArrayMap<F> for [T; $n]
                 where F: $fn_trait(T) -> U
             {
@@ -530,6 +1932,102 @@ mod impls {
                     [$(f($i),)*]
                 }
             }
+            impl<T, U> ArrayMapInto<U> for [T; $n]
+                where T: Into<U>
+            {
+                type Output = [U; $n];
+                fn map_into(array: Self) -> Self::Output {
+                    let [$($i,)*] = array;
+                    [$($i.into(),)*]
+                }
+            }
+            impl<T, U> ArrayTryMapInto<U> for [T; $n]
+                where T: TryInto<U>
+            {
+                type Output = [U; $n];
+                type Error = T::Error;
+                fn try_map_into(array: Self) -> Result<Self::Output, Self::Error> {
+                    let [$($i,)*] = array;
+                    Ok([$($i.try_into()?,)*])
+                }
+            }
+            impl<T, P> ArrayFind<T, P> for [T; $n]
+                where P: $fn_trait(&T) -> bool
+            {
+                fn find(array: Self, mut predicate: P) -> Option<T> {
+                    let [$($i,)*] = array;
+                    $(
+                        if predicate(&$i) {
+                            return Some($i);
+                        }
+                    )*
+                    None
+                }
+            }
+            impl<T> ArrayMinMax<T> for [T; $n+1] {
+                fn into_min_by<F>(array: Self, mut compare: F) -> (usize, T)
+                    where F: FnMut(&T, &T) -> Ordering
+                {
+                    let [first, $($i,)*] = array;
+                    let mut best = (0, first);
+                    let mut index = 0;
+                    $(
+                        index += 1;
+                        if compare(&$i, &best.1) == Ordering::Less {
+                            best = (index, $i);
+                        }
+                    )*
+                    best
+                }
+                fn into_max_by<F>(array: Self, mut compare: F) -> (usize, T)
+                    where F: FnMut(&T, &T) -> Ordering
+                {
+                    let [first, $($i,)*] = array;
+                    let mut best = (0, first);
+                    let mut index = 0;
+                    $(
+                        index += 1;
+                        if compare(&$i, &best.1) == Ordering::Greater {
+                            best = (index, $i);
+                        }
+                    )*
+                    best
+                }
+                fn into_min_by_key<K, F>(array: Self, mut f: F) -> (usize, T)
+                    where K: Ord, F: FnMut(&T) -> K
+                {
+                    let [first, $($i,)*] = array;
+                    let mut best_key = f(&first);
+                    let mut best = (0, first);
+                    let mut index = 0;
+                    $(
+                        index += 1;
+                        let key = f(&$i);
+                        if key < best_key {
+                            best_key = key;
+                            best = (index, $i);
+                        }
+                    )*
+                    best
+                }
+                fn into_max_by_key<K, F>(array: Self, mut f: F) -> (usize, T)
+                    where K: Ord, F: FnMut(&T) -> K
+                {
+                    let [first, $($i,)*] = array;
+                    let mut best_key = f(&first);
+                    let mut best = (0, first);
+                    let mut index = 0;
+                    $(
+                        index += 1;
+                        let key = f(&$i);
+                        if key > best_key {
+                            best_key = key;
+                            best = (index, $i);
+                        }
+                    )*
+                    best
+                }
+            }
             impl<T, U> ArrayZip<[U; $n]> for [T; $n] {
                 type Output = [(T, U); $n];
                 fn zip(array: Self, other: [U; $n]) -> Self::Output {
@@ -548,6 +2046,27 @@ mod impls {
                     [$(f($i,$j),)*]
                 }
             }
+            impl<T> ArrayCompare<T> for [T; $n] {
+                type Mask = [bool; $n];
+                fn eq_mask(array: Self, other: Self) -> Self::Mask where T: PartialEq {
+                    ArrayZipWith::zip_with(array, other, |a, b| a == b)
+                }
+                fn ne_mask(array: Self, other: Self) -> Self::Mask where T: PartialEq {
+                    ArrayZipWith::zip_with(array, other, |a, b| a != b)
+                }
+                fn lt_mask(array: Self, other: Self) -> Self::Mask where T: PartialOrd {
+                    ArrayZipWith::zip_with(array, other, |a, b| a < b)
+                }
+                fn le_mask(array: Self, other: Self) -> Self::Mask where T: PartialOrd {
+                    ArrayZipWith::zip_with(array, other, |a, b| a <= b)
+                }
+                fn gt_mask(array: Self, other: Self) -> Self::Mask where T: PartialOrd {
+                    ArrayZipWith::zip_with(array, other, |a, b| a > b)
+                }
+                fn ge_mask(array: Self, other: Self) -> Self::Mask where T: PartialOrd {
+                    ArrayZipWith::zip_with(array, other, |a, b| a >= b)
+                }
+            }
             impl<'a, T: 'a> ArrayAsRef<'a> for [T; $n]
             {
                 type Output = [&'a T; $n];
@@ -564,6 +2083,19 @@ mod impls {
                     [$($i,)*]
                 }
             }
+            impl<T> ArrayIntoNth<T> for [T; $n] {
+                fn into_nth<const I: usize>(array: Self) -> T {
+                    let [$($i,)*] = array;
+                    let mut index = 0;
+                    $(
+                        if index == I {
+                            return $i;
+                        }
+                        index += 1;
+                    )*
+                    unreachable!()
+                }
+            }
             impl<T> ArrayPush<T> for [T; $n] {
                 type Output = [T; $n+1];
                 fn push_back(array: Self, item: T) -> Self::Output {
@@ -574,6 +2106,11 @@ mod impls {
                     let [$($i,)*] = array;
                     [item, $($i,)*]
                 }
+                fn insert<const I: usize>(array: Self, item: T) -> Self::Output {
+                    let mut array = <Self as ArrayPush<T>>::push_back(array, item);
+                    array[I..].rotate_right(1);
+                    array
+                }
             }
             impl<T> ArrayPop<T> for [T; $n+1] {
                 type Output = [T; $n];
@@ -585,6 +2122,10 @@ mod impls {
                     let [item, $($i,)*] = array;
                     ([$($i,)*], item)
                 }
+                fn remove<const I: usize>(mut array: Self) -> (Self::Output, T) {
+                    array[I..].rotate_left(1);
+                    <Self as ArrayPop<T>>::pop_back(array)
+                }
             }
 
         )
@@ -624,6 +2165,147 @@ mod impls {
     impl_for_size!(30; FnMut => a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15 a16 a17 a18 a19 a20 a21 a22 a23 a24 a25 a26 a27 a28 a29 / b0 b1 b2 b3 b4 b5 b6 b7 b8 b9 b10 b11 b12 b13 b14 b15 b16 b17 b18 b19 b20 b21 b22 b23 b24 b25 b26 b27 b28 b29);
     impl_for_size!(31; FnMut => a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15 a16 a17 a18 a19 a20 a21 a22 a23 a24 a25 a26 a27 a28 a29 a30 / b0 b1 b2 b3 b4 b5 b6 b7 b8 b9 b10 b11 b12 b13 b14 b15 b16 b17 b18 b19 b20 b21 b22 b23 b24 b25 b26 b27 b28 b29 b30);
     impl_for_size!(32; FnMut => a0 a1 a2 a3 a4 a5 a6 a7 a8 a9 a10 a11 a12 a13 a14 a15 a16 a17 a18 a19 a20 a21 a22 a23 a24 a25 a26 a27 a28 a29 a30 a31 / b0 b1 b2 b3 b4 b5 b6 b7 b8 b9 b10 b11 b12 b13 b14 b15 b16 b17 b18 b19 b20 b21 b22 b23 b24 b25 b26 b27 b28 b29 b30 b31);
+
+
+    impl<T, const M: usize, const N: usize> ArrayNested for [[T; M]; N]
+        where [T; M]: ArrayTools, [<[T; M] as ArrayTools>::Tuple; N]: ArrayTools
+    {
+        type Nested = <[<[T; M] as ArrayTools>::Tuple; N] as ArrayTools>::Tuple;
+        fn into_nested_tuple(array: Self) -> Self::Nested {
+            array.map(ArrayTools::into_tuple).into_tuple()
+        }
+        fn from_nested_tuple(tuple: Self::Nested) -> Self {
+            <[<[T; M] as ArrayTools>::Tuple; N] as ArrayTools>::from_tuple(tuple).map(ArrayTools::from_tuple)
+        }
+    }
+
+    impl<T, const L: usize, const M: usize, const N: usize> ArrayNested3 for [[[T; L]; M]; N]
+        where [[T; L]; M]: ArrayNested, [<[[T; L]; M] as ArrayNested>::Nested; N]: ArrayTools
+    {
+        type Nested = <[<[[T; L]; M] as ArrayNested>::Nested; N] as ArrayTools>::Tuple;
+        fn into_nested_tuple3(array: Self) -> Self::Nested {
+            array.map(ArrayNested::into_nested_tuple).into_tuple()
+        }
+        fn from_nested_tuple3(tuple: Self::Nested) -> Self {
+            <[<[[T; L]; M] as ArrayNested>::Nested; N] as ArrayTools>::from_tuple(tuple).map(ArrayNested::from_nested_tuple)
+        }
+    }
+
+    impl<T, U, const N: usize, const M: usize> ArrayOuter<T, U, M> for [T; N] {
+        type Output<V> = [[V; M]; N];
+        fn outer_with<V, F>(array: Self, other: [U; M], mut f: F) -> Self::Output<V>
+            where T: Clone, U: Clone, F: FnMut(T, U) -> V
+        {
+            array.map(|t| other.clone().map(|u| f(t.clone(), u)))
+        }
+    }
+
+    impl<T: Clone, const N: usize, const K: usize> ArrayCombinations<K> for [T; N] {
+        type Output = Combinations<T, N, K>;
+        fn combinations(array: Self) -> Self::Output {
+            Combinations::new(array)
+        }
+    }
+
+    impl<T: Clone, const N: usize> ArrayPermutations for [T; N] {
+        type Output = Permutations<T, N>;
+        fn permutations(array: Self) -> Self::Output {
+            Permutations::new(array)
+        }
+    }
+
+    impl<const N: usize> MaskTools for [bool; N] {
+        type Array<T> = [T; N];
+        fn all_true(self) -> bool {
+            self.iter().all(|&m| m)
+        }
+        fn any_true(self) -> bool {
+            self.iter().any(|&m| m)
+        }
+        fn count_true(self) -> usize {
+            self.iter().filter(|&&m| m).count()
+        }
+        fn select<T>(self, if_true: [T; N], if_false: [T; N]) -> [T; N] {
+            Array(Array(self).zip(if_true)).zip_with(if_false, |(m, x), y| if m { x } else { y })
+        }
+    }
+
+    impl<T: FromStr, const N: usize> ArrayParse<T> for [T; N] {
+        fn parse_delimited(text: &str, separator: char) -> Result<Self, ParseArrayError<T::Err>> {
+            let fields = parse::Fields::new(text, separator);
+            let found = fields.count();
+            if found != N {
+                return Err(ParseArrayError::Count { expected: N, found });
+            }
+
+            let mut error = None;
+            let parsed = {
+                let mut values = fields.enumerate().map_while(|(index, field)| match field.parse() {
+                    Ok(value) => Some(value),
+                    Err(e) => {
+                        error = Some(ParseArrayError::Element { index, error: e });
+                        None
+                    }
+                });
+                iter::fill(&mut values)
+            };
+            // There are exactly `N` fields, so this only falls short on a parse error.
+            parsed.map_err(|_| error.unwrap())
+        }
+    }
+
+    impl<T, const N: usize> ArrayVecStorage for [T; N] {
+        type Slots = [Option<T>; N];
+    }
+
+    macro_rules! byte_conversions {
+        ($t:ty; $n:tt; $($to:ident $from:ident)*) => ($(
+            fn $to(array: Self) -> Self::Bytes {
+                let mut bytes = [0; $n * mem::size_of::<$t>()];
+                for (chunk, x) in bytes.chunks_exact_mut(mem::size_of::<$t>()).zip(array.iter()) {
+                    chunk.copy_from_slice(&x.$to());
+                }
+                bytes
+            }
+            fn $from(bytes: Self::Bytes) -> Self {
+                <[usize; $n] as ArrayIndices>::indices().map(|i| {
+                    let mut x = [0; mem::size_of::<$t>()];
+                    x.copy_from_slice(&bytes[i * mem::size_of::<$t>()..][..mem::size_of::<$t>()]);
+                    <$t>::$from(x)
+                })
+            }
+        )*)
+    }
+
+    macro_rules! impl_bytes {
+        ($($t:ty)*) => ($(
+            impl_bytes!(@sizes $t => 0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32);
+        )*);
+        (@sizes $t:ty => $($n:tt)*) => ($(
+            impl ArrayBytes for [$t; $n] {
+                type Bytes = [u8; $n * mem::size_of::<$t>()];
+                byte_conversions!($t; $n; to_le_bytes from_le_bytes to_be_bytes from_be_bytes to_ne_bytes from_ne_bytes);
+            }
+        )*);
+    }
+
+    impl_bytes!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64);
+
+    macro_rules! impl_number_bytes {
+        ($($t:ty)*) => ($(
+            impl NumberBytes for $t {
+                type Bytes = [u8; mem::size_of::<$t>()];
+                fn to_le_bytes(self) -> Self::Bytes { <$t>::to_le_bytes(self) }
+                fn to_be_bytes(self) -> Self::Bytes { <$t>::to_be_bytes(self) }
+                fn to_ne_bytes(self) -> Self::Bytes { <$t>::to_ne_bytes(self) }
+                fn from_le_bytes(bytes: Self::Bytes) -> Self { <$t>::from_le_bytes(bytes) }
+                fn from_be_bytes(bytes: Self::Bytes) -> Self { <$t>::from_be_bytes(bytes) }
+                fn from_ne_bytes(bytes: Self::Bytes) -> Self { <$t>::from_ne_bytes(bytes) }
+            }
+        )*)
+    }
+
+    impl_number_bytes!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64);
 }
 
 #[cfg(test)]
@@ -668,6 +2350,229 @@ mod tests {
         assert_eq!(sums, [31, 22, 13]);
     }
 
+    #[test]
+    fn bits_round_trip() {
+        use super::BitOrder::*;
+        use super::BitTools;
+
+        let flags = <[bool; 32]>::from_bits(0xDEAD_BEEF, LsbFirst);
+        assert_eq!(flags.to_bits(LsbFirst), 0xDEAD_BEEF);
+        assert_eq!(flags.to_bits(MsbFirst), 0xDEAD_BEEF_u32.reverse_bits());
+        assert_eq!(flags.pack_bits::<4>(LsbFirst), [0xEF, 0xBE, 0xAD, 0xDE]);
+        assert_eq!(<[bool; 32]>::unpack_bits(flags.pack_bits::<4>(MsbFirst), MsbFirst), flags);
+
+        let empty: [bool; 0] = [];
+        assert_eq!(empty.to_bits(MsbFirst), 0);
+        assert_eq!(empty.pack_bits::<0>(MsbFirst), []);
+
+        let flags = <[bool; 64]>::from_bits(0x0123_4567_89AB_CDEF, MsbFirst);
+        assert_eq!(flags.to_bits(MsbFirst), 0x0123_4567_89AB_CDEF);
+        assert_eq!(flags.pack_bits::<8>(MsbFirst), 0x0123_4567_89AB_CDEF_u64.to_be_bytes());
+        assert_eq!([false; 64].pack_bits::<8>(LsbFirst), [0; 8]);
+
+        let flags = <[bool; 100]>::from_bits(u128::MAX / 3, MsbFirst);
+        assert_eq!(flags.to_bits(MsbFirst), (u128::MAX / 3) & (u128::MAX >> 28));
+        assert_eq!(<[bool; 100]>::unpack_bits(flags.pack_bits::<13>(LsbFirst), LsbFirst), flags);
+
+        let flags = <[bool; 128]>::from_bits(u128::MAX / 3, LsbFirst);
+        assert_eq!(<[bool; 128]>::unpack_bits(flags.pack_bits::<16>(LsbFirst), LsbFirst), flags);
+        assert_eq!(flags.to_bits(LsbFirst), u128::MAX / 3);
+
+        let mut flags = [false; 200];
+        flags[199] = true;
+        let packed: [u8; 25] = flags.pack_bits(MsbFirst);
+        assert_eq!(packed[24], 1);
+        assert_eq!(<[bool; 200]>::unpack_bits(packed, MsbFirst), flags);
+    }
+
+    #[test]
+    fn length_error_is_an_error() {
+        let error: Box<dyn std::error::Error> = Box::new(<[u8; 3]>::from_slice_cloned(&[1, 2]).unwrap_err());
+        assert_eq!(error.to_string(), "expected 3 elements, but found 2");
+        assert!(error.source().is_none());
+    }
+
+    #[test]
+    fn parse_array_error_has_a_source() {
+        let error: Box<dyn std::error::Error> = Box::new(<[u8; 2]>::parse_delimited("1,x", ',').unwrap_err());
+        assert_eq!(error.to_string(), "invalid element at index 1: invalid digit found in string");
+        assert!(error.source().unwrap().is::<std::num::ParseIntError>());
+
+        let error: Box<dyn std::error::Error> = Box::new(<[u8; 2]>::parse_delimited("1", ',').unwrap_err());
+        assert!(error.source().is_none());
+    }
+
+    #[test]
+    fn array_chunks_keeps_the_remainder() {
+        use super::IteratorExt;
+
+        let mut chunks = IteratorExt::array_chunks::<4>(0..10);
+        assert_eq!(chunks.size_hint(), (2, Some(2)));
+        assert_eq!(chunks.next(), Some([0, 1, 2, 3]));
+        assert_eq!(chunks.next(), Some([4, 5, 6, 7]));
+        assert_eq!(chunks.into_remainder().collect::<Vec<_>>(), []);
+
+        let mut chunks = IteratorExt::array_chunks::<4>(0..10);
+        assert_eq!(chunks.by_ref().count(), 2);
+        assert_eq!(chunks.next(), None);
+        assert_eq!(chunks.into_remainder().collect::<Vec<_>>(), [8, 9]);
+
+        assert_eq!((0..0).collect_array::<0>(), Some([]));
+    }
+
+    #[test]
+    fn combinations_and_permutations_are_complete() {
+        let triples: Vec<_> = [0, 1, 2, 3, 4, 5, 6, 7].combinations::<3>().collect();
+        assert_eq!(triples.len(), 56);
+        assert!(triples.iter().all(|t| t[0] < t[1] && t[1] < t[2]));
+        assert_eq!(triples.last(), Some(&[5, 6, 7]));
+
+        assert_eq!([1, 2].combinations::<0>().collect::<Vec<_>>(), [[]]);
+        assert_eq!([1, 2].combinations::<3>().count(), 0);
+
+        let mut orders: Vec<_> = [1, 2, 3, 4].permutations().collect();
+        assert_eq!(orders.len(), 24);
+        orders.sort();
+        orders.dedup();
+        assert_eq!(orders.len(), 24);
+
+        let empty: [u8; 0] = [];
+        assert_eq!(empty.permutations().count(), 1);
+    }
+
+    #[test]
+    fn random_choices_stay_in_bounds() {
+        use super::random::below;
+        use super::RandomSource;
+
+        struct Counter(u64);
+        impl RandomSource for Counter {
+            fn next_u32(&mut self) -> u32 { (self.next_u64() >> 32) as u32 }
+            fn next_u64(&mut self) -> u64 {
+                self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+                self.0
+            }
+        }
+
+        let mut rng = Counter(0);
+        for bound in [1, 2, 3, 7, 1000, usize::MAX] {
+            for _ in 0..100 {
+                assert!(below(&mut rng, bound) < bound);
+            }
+        }
+
+        let chosen = [1, 2, 3, 4, 5, 6].choose_multiple::<6>(&mut rng);
+        assert_eq!(chosen.iter().sum::<i32>(), 21);
+        assert_eq!([7].shuffled(&mut rng), [7]);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn rand_core_generators_are_random_sources() {
+        use super::{RandCore, RandomSource};
+
+        // Counts up, so it's easy to see which calls were forwarded.
+        struct Counter(u64);
+        impl rand_core::RngCore for Counter {
+            fn next_u32(&mut self) -> u32 { self.next_u64() as u32 }
+            fn next_u64(&mut self) -> u64 {
+                self.0 += 1;
+                self.0
+            }
+            fn fill_bytes(&mut self, dst: &mut [u8]) {
+                rand_core::impls::fill_bytes_via_next(self, dst)
+            }
+        }
+
+        let mut rng = RandCore(Counter(0));
+        assert_eq!(rng.next_u64(), 1);
+        assert_eq!(rng.next_u32(), 2);
+
+        let mut inner = Counter(10);
+        let array = [1, 2, 3, 4, 5].shuffled(&mut RandCore(&mut inner));
+        assert!(inner.0 > 10);
+        assert_eq!(array.iter().sum::<i32>(), 15);
+    }
+
+    #[test]
+    fn range_from_does_not_accumulate_rounding() {
+        use super::Array;
+
+        let a = <[f32; 32]>::range_from(0.1, 0.1);
+        assert_eq!(a[31], 0.1 + 0.1 * 31.0);
+        let b = Array::<[f64; 1000]>::range_from(-0.3, 0.1);
+        assert_eq!(b[999], -0.3 + 0.1 * 999.0);
+    }
+
+    #[test]
+    fn parse_delimited_edge_cases() {
+        use super::ParseArrayError::*;
+
+        assert_eq!(<[u8; 0]>::parse_delimited("  ", ','), Ok([]));
+        assert_eq!(<[u8; 1]>::parse_delimited("", ','), Err(Count { expected: 1, found: 0 }));
+        assert_eq!(<[u8; 2]>::parse_delimited("\t1 \n 2\n", ' '), Ok([1, 2]));
+        assert_eq!(<[char; 2]>::parse_delimited("a→b", '→'), Ok(['a', 'b']));
+        assert!(matches!(<[u8; 3]>::parse_delimited("1,2,", ','), Err(Element { index: 2, .. })));
+        assert_eq!(
+            <[u8; 2]>::parse_delimited("1;x;3", ';').unwrap_err().to_string(),
+            "expected 2 elements, but found 3",
+        );
+    }
+
+    #[test]
+    fn array_vec_drain_closes_the_gap() {
+        use super::ArrayVec;
+
+        let mut v = ArrayVec::from([1, 2, 3, 4, 5, 6]);
+        let mut drain = v.drain(1..=3);
+        assert_eq!(drain.next_back(), Some(4));
+        drop(drain);
+        assert!(v.iter().eq(&[1, 5, 6]));
+
+        v.push(7);
+        v.insert(0, 0);
+        assert_eq!(v.drain(..2).len(), 2);
+        assert_eq!(v.drain(3..).count(), 0);
+        assert!(v.iter().eq(&[5, 6, 7]));
+
+        v.clear();
+        assert!(v.is_empty());
+        assert_eq!(v.into_array().unwrap_err().capacity(), 6);
+
+        let mut v = ArrayVec::from([1, 2, 3]);
+        std::mem::forget(v.drain(..));
+        assert_eq!(v.into_iter().count(), 0);
+    }
+
+    #[test]
+    fn array_vec_iterators_know_their_length() {
+        use super::ArrayVec;
+
+        let mut v = ArrayVec::<[i32; 8]>::new();
+        v.extend([1, 2, 3, 4, 5]);
+
+        let mut it = v.iter();
+        assert_eq!(it.len(), 5);
+        assert_eq!((it.next(), it.next_back()), (Some(&1), Some(&5)));
+        assert_eq!(it.size_hint(), (3, Some(3)));
+        assert!(it.rev().eq(&[4, 3, 2]));
+
+        let mut it = v.iter_mut();
+        *it.next_back().unwrap() = 50;
+        assert_eq!(it.len(), 4);
+
+        let mut it = v.into_iter();
+        assert_eq!(it.len(), 5);
+        assert_eq!(it.next_back(), Some(50));
+        assert_eq!(it.next(), Some(1));
+        assert_eq!(it.size_hint(), (3, Some(3)));
+        assert_eq!(it.collect::<Vec<_>>(), [2, 3, 4]);
+
+        let mut v = ArrayVec::from([1, 2, 3]);
+        std::mem::forget(v.drain(1..));
+        assert_eq!(v.into_iter().rev().collect::<Vec<_>>(), [1]);
+    }
+
     #[test]
     fn from_iter_is_not_ambiguous_with_std() {
         #[allow(unused_imports)]
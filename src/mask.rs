@@ -0,0 +1,101 @@
+use crate::traits::Sealed;
+
+/// An extension trait for arrays of `bool`s used as element-wise masks,
+/// such as the ones from [`ArrayTools::eq_mask`] and friends.
+///
+/// Use it with
+/// ```rust
+/// use arraytools::MaskTools;
+/// ```
+///
+/// It's implemented for masks of every length, including the ones from
+/// [`Array`](struct.Array.html).
+///
+/// Together these allow branchless, SIMD-style code on plain arrays:
+///
+/// ```rust
+/// use arraytools::{ArrayTools, MaskTools};
+///
+/// let x = [3, -1, 4, -1, 5];
+/// let negative = x.lt_mask([0; 5]);
+/// assert_eq!(negative.count_true(), 2);
+/// assert_eq!(negative.select(x.map(|v: i32| -v), x), [3, 1, 4, 1, 5]);
+/// ```
+///
+/// The methods are named `all_true` and so on, rather than `all`, so that
+/// they don't hide [`ArrayTools::all`] and [`ArrayTools::any`], which take
+/// a predicate, when both traits are in scope.
+///
+/// ```rust
+/// use arraytools::{ArrayTools, MaskTools};
+///
+/// let mask = [true, false];
+/// assert!(mask.any(|&b| b) && mask.any_true());
+/// ```
+///
+/// [`ArrayTools::eq_mask`]: trait.ArrayTools.html#method.eq_mask
+/// [`ArrayTools::all`]: trait.ArrayTools.html#method.all
+/// [`ArrayTools::any`]: trait.ArrayTools.html#method.any
+///
+/// (This trait is sealed; you are not allowed to implement it yourself.)
+pub trait MaskTools: Sized + Sealed {
+    /// The array type with the same length as this mask.
+    ///
+    /// ```rust
+    /// # type T = usize;
+    /// use arraytools::MaskTools;
+    ///
+    /// # fn _foo() where
+    /// [bool; 4]: MaskTools<Array<T> = [T; 4]>
+    /// # {}
+    /// ```
+    type Array<T>;
+
+    /// Returns whether every element of this mask is `true`.
+    ///
+    /// Type: `[bool; N] -> bool`
+    ///
+    /// ```rust
+    /// use arraytools::MaskTools;
+    ///
+    /// assert!([true, true].all_true());
+    /// assert!(![true, false].all_true());
+    /// ```
+    fn all_true(self) -> bool;
+
+    /// Returns whether any element of this mask is `true`.
+    ///
+    /// Type: `[bool; N] -> bool`
+    ///
+    /// ```rust
+    /// use arraytools::MaskTools;
+    ///
+    /// assert!([false, true].any_true());
+    /// assert!(![false, false].any_true());
+    /// ```
+    fn any_true(self) -> bool;
+
+    /// Returns the number of elements of this mask that are `true`.
+    ///
+    /// Type: `[bool; N] -> usize`
+    ///
+    /// ```rust
+    /// use arraytools::MaskTools;
+    ///
+    /// assert_eq!([true, false, true].count_true(), 2);
+    /// ```
+    fn count_true(self) -> usize;
+
+    /// Builds an array by picking each element from `if_true` where this
+    /// mask is `true`, and from `if_false` where it's `false`.
+    ///
+    /// Type: `([bool; N], [T; N], [T; N]) -> [T; N]`
+    ///
+    /// ```rust
+    /// use arraytools::MaskTools;
+    ///
+    /// let mask = [true, false, true];
+    /// assert_eq!(mask.select(["a", "b", "c"], ["x", "y", "z"]), ["a", "y", "c"]);
+    /// ```
+    fn select<T>(self, if_true: Self::Array<T>, if_false: Self::Array<T>) -> Self::Array<T>;
+}
@@ -0,0 +1,167 @@
+use core::cmp::Ordering;
+use core::marker::PhantomData;
+use core::num::{self, Wrapping};
+use core::time::Duration;
+
+use crate::ArrayTools;
+
+/// A type which can appear in arrays nested to any depth, and converted
+/// to and from the equivalent nested tuples.
+///
+/// Arrays of these become homogeneous tuples, by way of [`ArrayTools::Tuple`],
+/// and the conversion recurses into the elements.  Everything else is a leaf,
+/// which is left as it is.
+///
+/// [`ArrayTools::Tuple`]: trait.ArrayTools.html#associatedtype.Tuple
+///
+/// ```rust
+/// use arraytools::NestedElement;
+///
+/// let cube = [[[1, 2], [3, 4]], [[5, 6], [7, 8]]];
+/// assert_eq!(cube.into_nested(), (((1, 2), (3, 4)), ((5, 6), (7, 8))));
+/// ```
+///
+/// ```rust
+/// # #[cfg(feature = "alloc")] {
+/// use arraytools::NestedElement;
+///
+/// let s = |x: &str| x.to_string();
+/// let words = [[[s("a"), s("b")], [s("c"), s("d")]], [[s("e"), s("f")], [s("g"), s("h")]]];
+/// let (((a, _), _), (_, (_, h))) = words.into_nested();
+/// assert_eq!(a + &h, "ah");
+/// # }
+/// ```
+///
+/// This is implemented as a leaf for the primitive scalar types, `()`,
+/// references, tuples of up to 12 elements, `Option`, `Duration`, `Ordering`,
+/// `Wrapping`, `PhantomData` and the `NonZero` integers.  With the `alloc`
+/// feature, it's also a leaf for `String`, `Vec`, `VecDeque`, `BTreeMap`,
+/// `BTreeSet`, `Box`, `Rc`, `Arc` and `Cow`.
+///
+/// Unlike most traits here it isn't sealed, so you can mark your own types
+/// as leaves too:
+///
+/// ```rust
+/// use arraytools::NestedElement;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Meters(f64);
+/// impl NestedElement for Meters {
+///     type Nested = Self;
+///     fn into_nested(self) -> Self { self }
+///     fn from_nested(nested: Self) -> Self { nested }
+/// }
+///
+/// let path = [[[Meters(0.0), Meters(1.5)]], [[Meters(2.0), Meters(2.5)]]];
+/// let (((_, b),), ((c, _),)) = path.into_nested();
+/// assert_eq!((b, c), (Meters(1.5), Meters(2.0)));
+/// ```
+///
+/// The orphan rule means you can't do that for types from other crates,
+/// such as `HashMap`.  For those, use [`ArrayTools::into_nested_tuple`] or
+/// [`ArrayTools::into_nested_tuple3`], which convert a fixed number of
+/// levels, and work with any element type without needing this trait.
+///
+/// [`ArrayTools::into_nested_tuple`]: trait.ArrayTools.html#method.into_nested_tuple
+/// [`ArrayTools::into_nested_tuple3`]: trait.ArrayTools.html#method.into_nested_tuple3
+pub trait NestedElement: Sized {
+    /// The type this converts to: `Self` for a leaf, or the tuple of
+    /// converted elements for an array.
+    type Nested;
+
+    /// Converts this into its nested-tuple form.
+    fn into_nested(self) -> Self::Nested;
+
+    /// Converts back from the nested-tuple form.
+    fn from_nested(nested: Self::Nested) -> Self;
+}
+
+impl<T: NestedElement, const N: usize> NestedElement for [T; N]
+    where [T::Nested; N]: ArrayTools<Element = T::Nested>
+{
+    type Nested = <[T::Nested; N] as ArrayTools>::Tuple;
+
+    fn into_nested(self) -> Self::Nested {
+        self.map(T::into_nested).into_tuple()
+    }
+
+    fn from_nested(nested: Self::Nested) -> Self {
+        <[T::Nested; N]>::from_tuple(nested).map(T::from_nested)
+    }
+}
+
+macro_rules! impl_leaves {
+    ($([$($g:tt)*] $t:ty)+) => ($(
+        impl<$($g)*> NestedElement for $t {
+            type Nested = Self;
+            fn into_nested(self) -> Self { self }
+            fn from_nested(nested: Self) -> Self { nested }
+        }
+    )+);
+    ($($t:ty)+) => (
+        impl_leaves! { $([] $t)+ }
+    );
+}
+
+impl_leaves! {
+    () bool char
+    u8 u16 u32 u64 u128 usize
+    i8 i16 i32 i64 i128 isize
+    f32 f64
+    Duration Ordering
+    num::NonZeroU8 num::NonZeroU16 num::NonZeroU32 num::NonZeroU64 num::NonZeroU128 num::NonZeroUsize
+    num::NonZeroI8 num::NonZeroI16 num::NonZeroI32 num::NonZeroI64 num::NonZeroI128 num::NonZeroIsize
+}
+
+impl_leaves! {
+    [A] (A,)
+    [A, B] (A, B)
+    [A, B, C] (A, B, C)
+    [A, B, C, D] (A, B, C, D)
+    [A, B, C, D, E] (A, B, C, D, E)
+    [A, B, C, D, E, F] (A, B, C, D, E, F)
+    [A, B, C, D, E, F, G] (A, B, C, D, E, F, G)
+    [A, B, C, D, E, F, G, H] (A, B, C, D, E, F, G, H)
+    [A, B, C, D, E, F, G, H, I] (A, B, C, D, E, F, G, H, I)
+    [A, B, C, D, E, F, G, H, I, J] (A, B, C, D, E, F, G, H, I, J)
+    [A, B, C, D, E, F, G, H, I, J, K] (A, B, C, D, E, F, G, H, I, J, K)
+    [A, B, C, D, E, F, G, H, I, J, K, L] (A, B, C, D, E, F, G, H, I, J, K, L)
+}
+
+impl_leaves! {
+    ['a, T: ?Sized] &'a T
+    ['a, T: ?Sized] &'a mut T
+    [T] Option<T>
+    [T] Wrapping<T>
+    [T: ?Sized] PhantomData<T>
+}
+
+#[cfg(feature = "alloc")]
+mod alloc_leaves {
+    use alloc::borrow::{Cow, ToOwned};
+    use alloc::boxed::Box;
+    use alloc::collections::{BTreeMap, BTreeSet, VecDeque};
+    use alloc::rc::Rc;
+    use alloc::string::String;
+    #[cfg(target_has_atomic = "ptr")]
+    use alloc::sync::Arc;
+    use alloc::vec::Vec;
+
+    use super::NestedElement;
+
+    impl_leaves! {
+        [] String
+        [T] Vec<T>
+        [T] VecDeque<T>
+        [K, V] BTreeMap<K, V>
+        [T] BTreeSet<T>
+        [T: ?Sized] Box<T>
+        [T: ?Sized] Rc<T>
+        ['a, B: ?Sized + ToOwned] Cow<'a, B>
+    }
+
+    #[cfg(target_has_atomic = "ptr")]
+    impl_leaves! {
+        [T: ?Sized] Arc<T>
+    }
+}
@@ -0,0 +1,53 @@
+use crate::traits::{FloatOps, NumericOps, Sealed};
+
+/// The primitive integer and floating-point types, which can be the
+/// elements of [`ArrayTools::numeric_indices`] and [`ArrayTools::range_from`].
+///
+/// [`ArrayTools::numeric_indices`]: trait.ArrayTools.html#method.numeric_indices
+/// [`ArrayTools::range_from`]: trait.ArrayTools.html#method.range_from
+///
+/// (This trait is sealed; you are not allowed to implement it yourself.)
+pub trait Numeric: Sealed + NumericOps {}
+
+/// The primitive floating-point types, which can be the elements of
+/// [`ArrayTools::linspace`].
+///
+/// [`ArrayTools::linspace`]: trait.ArrayTools.html#method.linspace
+///
+/// (This trait is sealed; you are not allowed to implement it yourself.)
+pub trait Float: Numeric + FloatOps {}
+
+macro_rules! impl_numeric {
+    ($($t:ident)+) => ($(
+        impl Sealed for $t {}
+        impl Numeric for $t {}
+        impl NumericOps for $t {
+            fn from_index(i: usize) -> Self {
+                i as $t
+            }
+        }
+    )+)
+}
+
+impl_numeric!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64);
+
+macro_rules! impl_float {
+    ($($t:ident)+) => ($(
+        impl Float for $t {}
+        impl FloatOps for $t {
+            fn linspace_nth(start: Self, end: Self, i: usize, n: usize) -> Self {
+                // Pin both ends, so rounding can't move them (and a single
+                // point is just `start`, rather than a division by zero).
+                if i == 0 {
+                    start
+                } else if i + 1 == n {
+                    end
+                } else {
+                    start + (end - start) * (i as $t / (n - 1) as $t)
+                }
+            }
+        }
+    )+)
+}
+
+impl_float!(f32 f64);
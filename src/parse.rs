@@ -0,0 +1,84 @@
+use core::error::Error;
+use core::fmt;
+
+/// The error returned by [`ArrayTools::parse_delimited`].
+///
+/// [`ArrayTools::parse_delimited`]: trait.ArrayTools.html#method.parse_delimited
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ParseArrayError<E> {
+    /// The element at `index` couldn't be parsed.
+    Element {
+        /// The position of the element which failed, counting from zero.
+        index: usize,
+        /// The error from parsing that element.
+        error: E,
+    },
+    /// The text didn't contain the right number of elements.
+    Count {
+        /// The number of elements that were needed.
+        expected: usize,
+        /// The number of elements that were in the text.
+        found: usize,
+    },
+}
+
+impl<E: fmt::Display> fmt::Display for ParseArrayError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseArrayError::Element { index, error } => write!(f, "invalid element at index {}: {}", index, error),
+            ParseArrayError::Count { expected, found } => write!(f, "expected {} elements, but found {}", expected, found),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for ParseArrayError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseArrayError::Element { error, .. } => Some(error),
+            ParseArrayError::Count { .. } => None,
+        }
+    }
+}
+
+/// An iterator over the trimmed fields of delimited text, without allocating.
+///
+/// A whitespace separator splits on runs of any whitespace; any other
+/// separator splits on each occurrence.  Blank text has no fields.
+#[derive(Copy, Clone)]
+pub(crate) struct Fields<'a> {
+    rest: Option<&'a str>,
+    separator: char,
+}
+
+impl<'a> Fields<'a> {
+    pub(crate) fn new(text: &'a str, separator: char) -> Self {
+        let rest = if text.trim().is_empty() { None } else { Some(text) };
+        Fields { rest, separator }
+    }
+}
+
+impl<'a> Iterator for Fields<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let rest = self.rest?;
+        let (field, rest) = if self.separator.is_whitespace() {
+            let rest = rest.trim_start();
+            if rest.is_empty() {
+                self.rest = None;
+                return None;
+            }
+            match rest.find(char::is_whitespace) {
+                Some(i) => (&rest[..i], Some(&rest[i..])),
+                None => (rest, None),
+            }
+        } else {
+            match rest.find(self.separator) {
+                Some(i) => (&rest[..i], Some(&rest[i + self.separator.len_utf8()..])),
+                None => (rest, None),
+            }
+        };
+        self.rest = rest;
+        Some(field.trim())
+    }
+}
@@ -0,0 +1,125 @@
+use core::convert::TryFrom;
+
+/// A minimal source of random numbers, for [`ArrayTools::shuffle`] and friends.
+///
+/// This is deliberately small, so it's easy to implement over whatever
+/// generator a `no_std` target has.  With the `rand` feature, [`RandCore`]
+/// adapts any `rand_core::RngCore` into one.
+///
+/// [`ArrayTools::shuffle`]: trait.ArrayTools.html#method.shuffle
+/// [`RandCore`]: struct.RandCore.html
+///
+/// ```rust
+/// use arraytools::{ArrayTools, RandomSource};
+///
+/// /// Marsaglia's xorshift64, which is fine for shuffling but not for secrets.
+/// struct XorShift(u64);
+/// impl RandomSource for XorShift {
+///     fn next_u32(&mut self) -> u32 {
+///         (self.next_u64() >> 32) as u32
+///     }
+///     fn next_u64(&mut self) -> u64 {
+///         self.0 ^= self.0 << 13;
+///         self.0 ^= self.0 >> 7;
+///         self.0 ^= self.0 << 17;
+///         self.0
+///     }
+/// }
+///
+/// let mut rng = XorShift(0x2545_F491_4F6C_DD1D);
+/// let mut deck = <[usize; 32]>::indices();
+/// deck.shuffle(&mut rng);
+/// assert_ne!(deck, <[usize; 32]>::indices());
+///
+/// deck.sort();
+/// assert_eq!(deck, <[usize; 32]>::indices());
+/// ```
+pub trait RandomSource {
+    /// Returns the next random `u32`, with every value equally likely.
+    fn next_u32(&mut self) -> u32;
+
+    /// Returns the next random `u64`, with every value equally likely.
+    fn next_u64(&mut self) -> u64;
+}
+
+/// An adaptor which makes any `rand_core::RngCore` a [`RandomSource`].
+///
+/// Available with the `rand` feature.  As `&mut R` is an `RngCore` too,
+/// this can wrap a borrowed generator as well as an owned one.
+///
+/// [`RandomSource`]: trait.RandomSource.html
+///
+/// ```rust
+/// # #[cfg(feature = "rand")] {
+/// use arraytools::{ArrayTools, RandCore};
+///
+/// /// A linear congruential generator, which is fine for shuffling but not for secrets.
+/// struct Lcg(u64);
+/// impl rand_core::RngCore for Lcg {
+///     fn next_u32(&mut self) -> u32 { (self.next_u64() >> 32) as u32 }
+///     fn next_u64(&mut self) -> u64 {
+///         self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+///         self.0
+///     }
+///     fn fill_bytes(&mut self, dst: &mut [u8]) { rand_core::impls::fill_bytes_via_next(self, dst) }
+/// }
+///
+/// let mut rng = Lcg(42);
+/// let mut deck = <[usize; 32]>::indices();
+/// deck.shuffle(&mut RandCore(&mut rng));
+/// deck.sort();
+/// assert_eq!(deck, <[usize; 32]>::indices());
+/// # }
+/// ```
+#[cfg(feature = "rand")]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RandCore<R>(pub R);
+
+#[cfg(feature = "rand")]
+impl<R: rand_core::RngCore> RandomSource for RandCore<R> {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+}
+
+/// Returns a uniformly-distributed random number in `0..bound`.
+///
+/// This is Lemire's multiply-and-reject method, which avoids both the bias
+/// of a plain `%` and (almost always) the cost of a division.
+pub(crate) fn below<R: RandomSource + ?Sized>(rng: &mut R, bound: usize) -> usize {
+    debug_assert!(bound > 0);
+    if let Ok(bound) = u32::try_from(bound) {
+        let mut product = u64::from(rng.next_u32()) * u64::from(bound);
+        if (product as u32) < bound {
+            let threshold = bound.wrapping_neg() % bound;
+            while (product as u32) < threshold {
+                product = u64::from(rng.next_u32()) * u64::from(bound);
+            }
+        }
+        (product >> 32) as usize
+    } else {
+        let bound = bound as u64;
+        let mut product = u128::from(rng.next_u64()) * u128::from(bound);
+        if (product as u64) < bound {
+            let threshold = bound.wrapping_neg() % bound;
+            while (product as u64) < threshold {
+                product = u128::from(rng.next_u64()) * u128::from(bound);
+            }
+        }
+        (product >> 64) as usize
+    }
+}
+
+/// Moves a uniformly-chosen random selection of `count` elements, in random
+/// order, to the front of the slice; this is the first `count` steps of
+/// a Fisher-Yates shuffle.
+pub(crate) fn partial_shuffle<T, R: RandomSource + ?Sized>(slice: &mut [T], count: usize, rng: &mut R) {
+    for i in 0..count.min(slice.len().saturating_sub(1)) {
+        let j = i + below(rng, slice.len() - i);
+        slice.swap(i, j);
+    }
+}
@@ -0,0 +1,116 @@
+use core::convert::TryInto;
+
+use crate::traits::Sealed;
+
+/// An extension trait for getting fixed-length arrays out of slices.
+///
+/// Use it with
+/// ```rust
+/// use arraytools::SliceTools;
+/// ```
+///
+/// The arrays returned are ordinary references, so they work directly
+/// with [`ArrayTools`](trait.ArrayTools.html):
+///
+/// ```rust
+/// use arraytools::{ArrayTools, SliceTools};
+///
+/// let packet: &[u8] = &[0x00, 0x2A, 0xFF, 0xFF, 1, 2, 3];
+/// let (header, payload) = packet.split_array_ref::<4>().unwrap();
+/// assert_eq!(<[u16; 2]>::from_be_bytes(*header), [42, 0xFFFF]);
+/// assert_eq!(header.as_ref_array().map(|x| x.count_ones()), [0, 3, 8, 8]);
+/// assert_eq!(payload, [1, 2, 3]);
+/// ```
+///
+/// (This trait is sealed; you are not allowed to implement it yourself.)
+pub trait SliceTools<T>: Sealed {
+    /// Splits this slice into a leading array of `M` elements and the rest,
+    /// or returns `None` if it has fewer than `M` elements.
+    ///
+    /// Type: `&'a [T] -> Option<(&'a [T; M], &'a [T])>`
+    ///
+    /// ```rust
+    /// use arraytools::SliceTools;
+    ///
+    /// let slice = &[1, 2, 3][..];
+    /// assert_eq!(slice.split_array_ref::<2>(), Some((&[1, 2], &[3][..])));
+    /// assert_eq!(slice.split_array_ref::<4>(), None);
+    /// ```
+    fn split_array_ref<const M: usize>(&self) -> Option<(&[T; M], &[T])>;
+
+    /// Splits this slice into a leading mutable array of `M` elements and the rest,
+    /// or returns `None` if it has fewer than `M` elements.
+    ///
+    /// Type: `&'a mut [T] -> Option<(&'a mut [T; M], &'a mut [T])>`
+    ///
+    /// ```rust
+    /// use arraytools::SliceTools;
+    ///
+    /// let slice = &mut [1, 2, 3][..];
+    /// let (head, tail) = slice.split_array_mut::<1>().unwrap();
+    /// head[0] = 10;
+    /// tail[1] = 30;
+    /// assert_eq!(slice, [10, 2, 30]);
+    /// ```
+    fn split_array_mut<const M: usize>(&mut self) -> Option<(&mut [T; M], &mut [T])>;
+
+    /// Splits this slice into the rest and a trailing array of `M` elements,
+    /// or returns `None` if it has fewer than `M` elements.
+    ///
+    /// Type: `&'a [T] -> Option<(&'a [T], &'a [T; M])>`
+    ///
+    /// ```rust
+    /// use arraytools::SliceTools;
+    ///
+    /// let slice = &[1, 2, 3][..];
+    /// assert_eq!(slice.rsplit_array_ref::<2>(), Some((&[1][..], &[2, 3])));
+    /// assert_eq!(slice.rsplit_array_ref::<4>(), None);
+    /// ```
+    fn rsplit_array_ref<const M: usize>(&self) -> Option<(&[T], &[T; M])>;
+
+    /// Splits this slice into the rest and a trailing mutable array of `M` elements,
+    /// or returns `None` if it has fewer than `M` elements.
+    ///
+    /// Type: `&'a mut [T] -> Option<(&'a mut [T], &'a mut [T; M])>`
+    ///
+    /// ```rust
+    /// use arraytools::SliceTools;
+    ///
+    /// let slice = &mut [1, 2, 3][..];
+    /// let (_, last) = slice.rsplit_array_mut::<1>().unwrap();
+    /// last[0] *= 10;
+    /// assert_eq!(slice, [1, 2, 30]);
+    /// ```
+    fn rsplit_array_mut<const M: usize>(&mut self) -> Option<(&mut [T], &mut [T; M])>;
+}
+
+impl<T> Sealed for [T] {}
+impl<T> SliceTools<T> for [T] {
+    fn split_array_ref<const M: usize>(&self) -> Option<(&[T; M], &[T])> {
+        if self.len() < M {
+            return None;
+        }
+        let (head, tail) = self.split_at(M);
+        Some((head.try_into().ok()?, tail))
+    }
+
+    fn split_array_mut<const M: usize>(&mut self) -> Option<(&mut [T; M], &mut [T])> {
+        if self.len() < M {
+            return None;
+        }
+        let (head, tail) = self.split_at_mut(M);
+        Some((head.try_into().ok()?, tail))
+    }
+
+    fn rsplit_array_ref<const M: usize>(&self) -> Option<(&[T], &[T; M])> {
+        let mid = self.len().checked_sub(M)?;
+        let (init, last) = self.split_at(mid);
+        Some((init, last.try_into().ok()?))
+    }
+
+    fn rsplit_array_mut<const M: usize>(&mut self) -> Option<(&mut [T], &mut [T; M])> {
+        let mid = self.len().checked_sub(M)?;
+        let (init, last) = self.split_at_mut(mid);
+        Some((init, last.try_into().ok()?))
+    }
+}
@@ -0,0 +1,114 @@
+use crate::traits::*;
+use crate::ArrayTools;
+
+/// An extension trait for working with homogeneous tuples, by way of the
+/// equivalent arrays.
+///
+/// Use it with
+/// ```rust
+/// use arraytools::TupleTools;
+/// ```
+///
+/// This is implemented for tuples of between 0 and 32 elements of the same
+/// type `T`.  As the empty tuple doesn't determine an element type, it's
+/// implemented for every `T`, which is inferred from how it's used.
+///
+/// ```rust
+/// use arraytools::TupleTools;
+///
+/// assert_eq!((1, 2, 3).into_array(), [1, 2, 3]);
+/// assert_eq!((1, 2).map(|x| x * 10), (10, 20));
+///
+/// let empty: [String; 0] = ().into_array();
+/// assert_eq!(empty.len(), 0);
+/// ```
+///
+/// (This trait is sealed; you are not allowed to implement it yourself.)
+pub trait TupleTools<T>: Sized + Sealed {
+    /// The array type equivalent to this homogeneous tuple type.
+    ///
+    /// This is the inverse of [`ArrayTools::Tuple`].
+    ///
+    /// [`ArrayTools::Tuple`]: trait.ArrayTools.html#associatedtype.Tuple
+    ///
+    /// ```rust
+    /// # type T = usize;
+    /// use arraytools::TupleTools;
+    ///
+    /// # fn _foo() where
+    /// (T, T, T): TupleTools<T, Array = [T; 3]>,
+    /// (): TupleTools<T, Array = [T; 0]>
+    /// # {}
+    /// ```
+    type Array: ArrayTools<Tuple = Self>;
+
+    /// Converts this homogeneous tuple into the equivalent array.
+    ///
+    /// Type: `(T, T, ..., T) -> [T; N]`
+    ///
+    /// ```rust
+    /// use arraytools::TupleTools;
+    ///
+    /// assert_eq!(("a", "b").into_array(), ["a", "b"]);
+    /// ```
+    fn into_array(self) -> Self::Array {
+        ArrayTools::from_tuple(self)
+    }
+
+    /// Builds a new tuple by applying the provided function to each element of this tuple.
+    ///
+    /// Type: `((T, T, ..., T), F) -> (U, U, ..., U)`
+    /// - when `N <= 1` this requires `F: FnOnce(T) -> U`
+    /// - when `N > 1` this requires `F: FnMut(T) -> U`
+    ///
+    /// ```rust
+    /// use arraytools::TupleTools;
+    ///
+    /// assert_eq!((1, 10, 100).map(|x| x + 10), (11, 20, 110));
+    /// ```
+    #[must_use = "this returns the new tuple; it doesn't update the existing one"]
+    fn map<F>(self, f: F) -> <<Self::Array as ArrayMap<F>>::Output as ArrayTools>::Tuple
+        where Self::Array: ArrayMap<F>, <Self::Array as ArrayMap<F>>::Output: ArrayTools
+    {
+        self.into_array().map(f).into_tuple()
+    }
+
+    /// Combines two homogeneous tuples of the same length into a tuple of pairs.
+    ///
+    /// Type: `((T, T, ..., T), (U, U, ..., U)) -> ((T, U), (T, U), ..., (T, U))`
+    ///
+    /// ```rust
+    /// use arraytools::TupleTools;
+    ///
+    /// assert_eq!((1, 2).zip(("one", "two")), ((1, "one"), (2, "two")));
+    /// ```
+    fn zip<U, V>(self, other: U) -> <<Self::Array as ArrayZip<U::Array>>::Output as ArrayTools>::Tuple
+        where U: TupleTools<V>, Self::Array: ArrayZip<U::Array>, <Self::Array as ArrayZip<U::Array>>::Output: ArrayTools
+    {
+        self.into_array().zip(other.into_array()).into_tuple()
+    }
+
+    /// The tuple of references to the elements of this tuple.
+    ///
+    /// ```rust
+    /// # type T = usize;
+    /// use arraytools::TupleTools;
+    ///
+    /// # fn _foo<'a>() where
+    /// (T, T): TupleTools<T, RefTuple<'a> = (&'a T, &'a T)>
+    /// # {}
+    /// ```
+    type RefTuple<'a> where Self: 'a;
+
+    /// Builds a tuple of references to the elements of this tuple.
+    ///
+    /// Type: `&'a (T, T, ..., T) -> (&'a T, &'a T, ..., &'a T)`
+    ///
+    /// ```rust
+    /// use arraytools::TupleTools;
+    ///
+    /// let tuple = ("hello".to_string(), "world".to_string());
+    /// assert_eq!(tuple.as_ref_tuple().map(|s| s.len()), (5, 5));
+    /// ```
+    fn as_ref_tuple(&self) -> Self::RefTuple<'_>;
+}
@@ -0,0 +1,982 @@
+use core::borrow::{Borrow, BorrowMut};
+use core::cmp::Ordering;
+use core::convert::TryInto;
+use core::hash::{Hash, Hasher};
+use core::iter::FromIterator;
+use core::ops::{Deref, DerefMut, Index, IndexMut};
+use core::slice::{self, SliceIndex};
+use core::str::FromStr;
+use core::{array, fmt, mem};
+
+use crate::display::DisplayWith;
+use crate::iter::fill;
+use crate::random::{self, RandomSource};
+use crate::traits::{ArrayBits, ArrayOuter, ArrayParse, NumberBytes};
+use crate::{BitOrder, BitTools, Combinations, Float, LengthError, Numeric, ParseArrayError, Permutations};
+
+/// A wrapper around an array which implements the common traits for
+/// every length, not just the lengths up to 32.
+///
+/// `Array<[T; N]>` has the same layout as `[T; N]`, and derefs to it.
+/// It also has its own versions of the [`ArrayTools`] methods, such as
+/// [`.map()`](#method.map) and [`.as_ref_array()`](#method.as_ref_array),
+/// which work for every length.  The exceptions are the conversions to
+/// and from tuples, which only exist for lengths up to 32, and `from_iter`,
+/// for which use `collect` or [`IteratorExt::collect_array`] instead.
+///
+/// Where the length of the result differs from `N`, as with
+/// [`.push_back()`](#method.push_back), it's a separate parameter that's
+/// checked at compile time.  The [`MaskTools`] and [`BitTools`] traits are
+/// implemented for every length, so they work directly on the arrays here.
+///
+/// [`ArrayTools`]: trait.ArrayTools.html
+/// [`IteratorExt::collect_array`]: trait.IteratorExt.html#method.collect_array
+/// [`MaskTools`]: trait.MaskTools.html
+/// [`BitTools`]: trait.BitTools.html
+///
+/// ```rust
+/// use arraytools::Array;
+///
+/// let mut a: Array<[u8; 40]> = Default::default();
+/// a[39] = 7;
+/// assert!(a > Array([0; 40]));
+/// assert_eq!(a.as_ref_array()[39], &7);
+/// assert_eq!(a.position(|&x| x > 0), Some(39));
+/// assert_eq!(a.map(|x| x * 2)[39], 14);
+///
+/// let b = Array([1, 2, 3]);
+/// assert_eq!(b.zip(["one", "two", "three"])[1], (2, "two"));
+/// ```
+///
+/// ```rust
+/// use arraytools::Array;
+///
+/// let mut a: Array<[u32; 100]> = (0..100).collect();
+/// assert_eq!(a.as_ref_array()[99], &99);
+/// *a.as_mut_array()[0] = 100;
+/// *a.get_nth_mut::<1>() = 101;
+/// assert_eq!(a.get_nth::<1>(), &101);
+/// assert_eq!(a.position(|&x| x == 50), Some(50));
+/// assert!(a.any(|&x| x == 101) && a.all(|&x| x > 1));
+/// assert_eq!(a.count_where(|&x| x % 10 == 0), 10);
+/// assert_eq!(a.map(|x| x * 2)[2], 4);
+/// assert_eq!(a.zip([(); 100])[3], (3, ()));
+/// assert_eq!(a.zip_with(a.0, |x, y| x + y)[4], 8);
+/// assert_eq!(a.find(|&x| x % 2 == 1), Some(101));
+/// assert_eq!(a.into_nth::<99>(), 99);
+/// assert_eq!(a.replace::<2>(7).1, 2);
+/// assert_eq!(Array([1, 2, 3]).display_with(", ").to_string(), "1, 2, 3");
+///
+/// let mut sum = 0;
+/// a.for_each(|x| sum += x);
+/// assert_eq!(sum, (2..100).sum::<u32>() + 201);
+/// ```
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+pub struct Array<A>(pub A);
+
+impl<A> Array<A> {
+    /// Unwraps this into the array it contains.
+    ///
+    /// ```rust
+    /// use arraytools::Array;
+    ///
+    /// assert_eq!(Array([1, 2]).into_inner(), [1, 2]);
+    /// ```
+    pub fn into_inner(self) -> A {
+        self.0
+    }
+}
+
+impl<T, const N: usize> Array<[T; N]> {
+    /// Like [`ArrayTools::generate`](trait.ArrayTools.html#method.generate), for any length.
+    ///
+    /// Type: `impl FnMut() -> T -> Array<[T; N]>`
+    pub fn generate<F: FnMut() -> T>(mut f: F) -> Self {
+        Array(array::from_fn(|_| f()))
+    }
+
+    /// Like [`ArrayTools::repeat`](trait.ArrayTools.html#method.repeat), for any length.
+    ///
+    /// Type: `T -> Array<[T; N]>`, where `T: Clone`
+    pub fn repeat(x: T) -> Self
+        where T: Clone
+    {
+        Array(array::from_fn(|_| x.clone()))
+    }
+
+    /// Like [`ArrayTools::from_slice_cloned`](trait.ArrayTools.html#method.from_slice_cloned), for any length.
+    ///
+    /// Type: `&[T] -> Result<Array<[T; N]>, LengthError>`, where `T: Clone`
+    pub fn from_slice_cloned(slice: &[T]) -> Result<Self, LengthError>
+        where T: Clone
+    {
+        if slice.len() != N {
+            return Err(LengthError::new(N, slice.len()));
+        }
+        Self::from_slice_prefix(slice)
+    }
+
+    /// Like [`ArrayTools::from_slice_prefix`](trait.ArrayTools.html#method.from_slice_prefix), for any length.
+    ///
+    /// Type: `&[T] -> Result<Array<[T; N]>, LengthError>`, where `T: Clone`
+    pub fn from_slice_prefix(slice: &[T]) -> Result<Self, LengthError>
+        where T: Clone
+    {
+        fill(&mut slice.iter().cloned())
+            .map(Array)
+            .map_err(|_| LengthError::new(N, slice.len()))
+    }
+
+    /// Like [`ArrayTools::parse_delimited`](trait.ArrayTools.html#method.parse_delimited), for any length.
+    ///
+    /// Type: `(&str, char) -> Result<Array<[T; N]>, ParseArrayError<T::Err>>`, where `T: FromStr`
+    pub fn parse_delimited(text: &str, separator: char) -> Result<Self, ParseArrayError<T::Err>>
+        where T: FromStr
+    {
+        ArrayParse::parse_delimited(text, separator).map(Array)
+    }
+
+    /// Like [`ArrayTools::numeric_indices`](trait.ArrayTools.html#method.numeric_indices), for any length.
+    ///
+    /// Type: `() -> Array<[T; N]>`, where `T: Numeric`
+    pub fn numeric_indices() -> Self
+        where T: Numeric
+    {
+        Array(array::from_fn(T::from_index))
+    }
+
+    /// Like [`ArrayTools::range_from`](trait.ArrayTools.html#method.range_from), for any length.
+    ///
+    /// Type: `(T, T) -> Array<[T; N]>`, where `T: Numeric`
+    ///
+    /// ```rust
+    /// use arraytools::Array;
+    ///
+    /// let evens = Array::<[u32; 50]>::range_from(0, 2);
+    /// assert_eq!(evens[49], 98);
+    /// ```
+    pub fn range_from(start: T, step: T) -> Self
+        where T: Numeric
+    {
+        Array(array::from_fn(|i| start + step * T::from_index(i)))
+    }
+
+    /// Like [`ArrayTools::linspace`](trait.ArrayTools.html#method.linspace), for any length.
+    ///
+    /// Type: `(T, T) -> Array<[T; N]>`, where `T: Float`
+    pub fn linspace(start: T, end: T) -> Self
+        where T: Float
+    {
+        Array(array::from_fn(|i| T::linspace_nth(start, end, i, N)))
+    }
+
+    /// Like [`ArrayTools::map`](trait.ArrayTools.html#method.map), for any length.
+    ///
+    /// Type: `(Array<[T; N]>, impl FnMut(T) -> U) -> [U; N]`
+    #[must_use = "if you don't need the result, use `for_each`"]
+    pub fn map<U, F: FnMut(T) -> U>(self, f: F) -> [U; N] {
+        self.0.map(f)
+    }
+
+    /// Like [`ArrayTools::for_each`](trait.ArrayTools.html#method.for_each), for any length.
+    ///
+    /// Type: `(Array<[T; N]>, impl FnMut(T))`
+    pub fn for_each<F: FnMut(T)>(self, f: F) {
+        IntoIterator::into_iter(self.0).for_each(f)
+    }
+
+    /// Like [`ArrayTools::map_into`](trait.ArrayTools.html#method.map_into), for any length.
+    ///
+    /// Type: `Array<[T; N]> -> [U; N]`, where `T: Into<U>`
+    #[must_use = "this returns the new array; it doesn't update the existing one"]
+    pub fn map_into<U>(self) -> [U; N]
+        where T: Into<U>
+    {
+        self.0.map(Into::into)
+    }
+
+    /// Like [`ArrayTools::try_map_into`](trait.ArrayTools.html#method.try_map_into), for any length.
+    ///
+    /// Type: `Array<[T; N]> -> Result<[U; N], <T as TryInto<U>>::Error>`
+    ///
+    /// ```rust
+    /// use arraytools::Array;
+    ///
+    /// let mut wide = Array([1_i32; 40]);
+    /// assert_eq!(wide.try_map_into::<u8>(), Ok([1_u8; 40]));
+    /// wide[39] = -1;
+    /// assert!(wide.try_map_into::<u8>().is_err());
+    /// ```
+    pub fn try_map_into<U>(self) -> Result<[U; N], T::Error>
+        where T: TryInto<U>
+    {
+        let mut error = None;
+        let converted = {
+            let mut values = IntoIterator::into_iter(self.0).map_while(|x| match x.try_into() {
+                Ok(value) => Some(value),
+                Err(e) => {
+                    error = Some(e);
+                    None
+                }
+            });
+            fill(&mut values)
+        };
+        // There are exactly `N` elements, so this only falls short on an error.
+        converted.map_err(|_| error.unwrap())
+    }
+
+    /// Like [`ArrayTools::find`](trait.ArrayTools.html#method.find), for any length.
+    ///
+    /// Type: `(Array<[T; N]>, impl FnMut(&T) -> bool) -> Option<T>`
+    pub fn find<P: FnMut(&T) -> bool>(self, predicate: P) -> Option<T> {
+        IntoIterator::into_iter(self.0).find(predicate)
+    }
+
+    /// Like [`ArrayTools::position`](trait.ArrayTools.html#method.position), for any length.
+    ///
+    /// Type: `(&Array<[T; N]>, impl FnMut(&T) -> bool) -> Option<usize>`
+    pub fn position<P: FnMut(&T) -> bool>(&self, predicate: P) -> Option<usize> {
+        self.0.iter().position(predicate)
+    }
+
+    /// Like [`ArrayTools::any`](trait.ArrayTools.html#method.any), for any length.
+    ///
+    /// Type: `(&Array<[T; N]>, impl FnMut(&T) -> bool) -> bool`
+    pub fn any<P: FnMut(&T) -> bool>(&self, predicate: P) -> bool {
+        self.0.iter().any(predicate)
+    }
+
+    /// Like [`ArrayTools::all`](trait.ArrayTools.html#method.all), for any length.
+    ///
+    /// Type: `(&Array<[T; N]>, impl FnMut(&T) -> bool) -> bool`
+    pub fn all<P: FnMut(&T) -> bool>(&self, predicate: P) -> bool {
+        self.0.iter().all(predicate)
+    }
+
+    /// Like [`ArrayTools::count_where`](trait.ArrayTools.html#method.count_where), for any length.
+    ///
+    /// Type: `(&Array<[T; N]>, impl FnMut(&T) -> bool) -> usize`
+    pub fn count_where<P: FnMut(&T) -> bool>(&self, mut predicate: P) -> usize {
+        self.0.iter().filter(|x| predicate(x)).count()
+    }
+
+    /// Like [`ArrayTools::argmin`](trait.ArrayTools.html#method.argmin), for any non-zero length.
+    ///
+    /// Type: `&Array<[T; N]> -> usize`, where `N >= 1` and `T: Ord`
+    pub fn argmin(&self) -> usize
+        where T: Ord
+    {
+        self.argmin_by(Ord::cmp)
+    }
+
+    /// Like [`ArrayTools::argmax`](trait.ArrayTools.html#method.argmax), for any non-zero length.
+    ///
+    /// Type: `&Array<[T; N]> -> usize`, where `N >= 1` and `T: Ord`
+    pub fn argmax(&self) -> usize
+        where T: Ord
+    {
+        self.argmax_by(Ord::cmp)
+    }
+
+    /// Like [`ArrayTools::argmin_by`](trait.ArrayTools.html#method.argmin_by), for any non-zero length.
+    ///
+    /// Type: `(&Array<[T; N]>, impl FnMut(&T, &T) -> Ordering) -> usize`, where `N >= 1`
+    ///
+    /// ```rust
+    /// use arraytools::Array;
+    ///
+    /// let mut a = Array([5.0; 40]);
+    /// a[33] = -1.0;
+    /// assert_eq!(a.argmin_by(f64::total_cmp), 33);
+    /// assert_eq!(a.argmax_by(f64::total_cmp), 0);
+    /// ```
+    ///
+    /// An empty array has no smallest element, which is a compile error:
+    ///
+    /// ```rust,compile_fail
+    /// use arraytools::Array;
+    ///
+    /// Array::<[f64; 0]>([]).argmin_by(f64::total_cmp);
+    /// ```
+    pub fn argmin_by<F>(&self, mut compare: F) -> usize
+        where F: FnMut(&T, &T) -> Ordering
+    {
+        const { assert!(N >= 1, "the array must not be empty") };
+        (1..N).fold(0, |best, i| {
+            if compare(&self.0[i], &self.0[best]) == Ordering::Less { i } else { best }
+        })
+    }
+
+    /// Like [`ArrayTools::argmax_by`](trait.ArrayTools.html#method.argmax_by), for any non-zero length.
+    ///
+    /// Type: `(&Array<[T; N]>, impl FnMut(&T, &T) -> Ordering) -> usize`, where `N >= 1`
+    pub fn argmax_by<F>(&self, mut compare: F) -> usize
+        where F: FnMut(&T, &T) -> Ordering
+    {
+        const { assert!(N >= 1, "the array must not be empty") };
+        (1..N).fold(0, |best, i| {
+            if compare(&self.0[i], &self.0[best]) == Ordering::Greater { i } else { best }
+        })
+    }
+
+    /// Like [`ArrayTools::into_min_by_key`](trait.ArrayTools.html#method.into_min_by_key), for any non-zero length.
+    ///
+    /// Type: `(Array<[T; N]>, impl FnMut(&T) -> K) -> (usize, T)`, where `N >= 1` and `K: Ord`
+    ///
+    /// ```rust
+    /// use arraytools::Array;
+    ///
+    /// let mut words = Array::<[String; 40]>::default();
+    /// words[7] = "tangerine".to_string();
+    /// assert_eq!(words.into_max_by_key(|w| w.len()), (7, "tangerine".to_string()));
+    /// ```
+    pub fn into_min_by_key<K, F>(self, f: F) -> (usize, T)
+        where K: Ord, F: FnMut(&T) -> K
+    {
+        self.into_best_by_key(f, |a, b| a.0.cmp(b.0), Ordering::Less)
+    }
+
+    /// Like [`ArrayTools::into_max_by_key`](trait.ArrayTools.html#method.into_max_by_key), for any non-zero length.
+    ///
+    /// Type: `(Array<[T; N]>, impl FnMut(&T) -> K) -> (usize, T)`, where `N >= 1` and `K: Ord`
+    pub fn into_max_by_key<K, F>(self, f: F) -> (usize, T)
+        where K: Ord, F: FnMut(&T) -> K
+    {
+        self.into_best_by_key(f, |a, b| a.0.cmp(b.0), Ordering::Greater)
+    }
+
+    /// Like [`ArrayTools::into_min_by`](trait.ArrayTools.html#method.into_min_by), for any non-zero length.
+    ///
+    /// Type: `(Array<[T; N]>, impl FnMut(&T, &T) -> Ordering) -> (usize, T)`, where `N >= 1`
+    pub fn into_min_by<F>(self, mut compare: F) -> (usize, T)
+        where F: FnMut(&T, &T) -> Ordering
+    {
+        self.into_best_by_key(|_| (), |a, b| compare(a.1, b.1), Ordering::Less)
+    }
+
+    /// Like [`ArrayTools::into_max_by`](trait.ArrayTools.html#method.into_max_by), for any non-zero length.
+    ///
+    /// Type: `(Array<[T; N]>, impl FnMut(&T, &T) -> Ordering) -> (usize, T)`, where `N >= 1`
+    pub fn into_max_by<F>(self, mut compare: F) -> (usize, T)
+        where F: FnMut(&T, &T) -> Ordering
+    {
+        self.into_best_by_key(|_| (), |a, b| compare(a.1, b.1), Ordering::Greater)
+    }
+
+    // Keeps the first element that compares as `wanted` against all the
+    // earlier ones, computing each element's key only once.
+    fn into_best_by_key<K, G, F>(self, mut key: G, mut compare: F, wanted: Ordering) -> (usize, T)
+        where G: FnMut(&T) -> K, F: FnMut((&K, &T), (&K, &T)) -> Ordering
+    {
+        const { assert!(N >= 1, "the array must not be empty") };
+        let mut items = IntoIterator::into_iter(self.0).enumerate().map(|(i, x)| (key(&x), i, x));
+        let first = items.next().unwrap();
+        let (_, index, item) = items.fold(first, |best, x| {
+            if compare((&x.0, &x.2), (&best.0, &best.2)) == wanted { x } else { best }
+        });
+        (index, item)
+    }
+
+    /// Like [`ArrayTools::zip`](trait.ArrayTools.html#method.zip), for any length.
+    ///
+    /// Type: `(Array<[T; N]>, [U; N]) -> [(T, U); N]`
+    pub fn zip<U>(self, other: [U; N]) -> [(T, U); N] {
+        self.zip_with(other, |t, u| (t, u))
+    }
+
+    /// Like [`ArrayTools::zip_with`](trait.ArrayTools.html#method.zip_with), for any length.
+    ///
+    /// Type: `(Array<[T; N]>, [U; N], impl FnMut(T, U) -> V) -> [V; N]`
+    pub fn zip_with<U, V, F: FnMut(T, U) -> V>(self, other: [U; N], mut f: F) -> [V; N] {
+        let mut other = IntoIterator::into_iter(other);
+        // Both arrays have `N` elements, so `other` can't run out.
+        self.0.map(|t| f(t, other.next().unwrap()))
+    }
+
+    /// Like [`ArrayTools::cartesian_product`](trait.ArrayTools.html#method.cartesian_product), for any lengths.
+    ///
+    /// Type: `(Array<[T; N]>, [U; M]) -> [[(T, U); M]; N]`, where `T: Clone, U: Clone`
+    pub fn cartesian_product<U, const M: usize>(self, other: [U; M]) -> [[(T, U); M]; N]
+        where T: Clone, U: Clone
+    {
+        self.outer_with(other, |t, u| (t, u))
+    }
+
+    /// Like [`ArrayTools::outer_with`](trait.ArrayTools.html#method.outer_with), for any lengths.
+    ///
+    /// Type: `(Array<[T; N]>, [U; M], impl FnMut(T, U) -> V) -> [[V; M]; N]`, where `T: Clone, U: Clone`
+    pub fn outer_with<U, V, F, const M: usize>(self, other: [U; M], f: F) -> [[V; M]; N]
+        where T: Clone, U: Clone, F: FnMut(T, U) -> V
+    {
+        ArrayOuter::outer_with(self.0, other, f)
+    }
+
+    /// Like [`ArrayTools::display_with`](trait.ArrayTools.html#method.display_with), for any length.
+    ///
+    /// Type: `(&'a Array<[T; N]>, &'a str) -> impl Display + 'a`, where `T: Display`
+    pub fn display_with<'a>(&'a self, separator: &'a str) -> DisplayWith<'a, T> {
+        DisplayWith::new(&self.0, separator)
+    }
+
+    /// Like [`ArrayTools::shuffle`](trait.ArrayTools.html#method.shuffle), for any length.
+    ///
+    /// Type: `(&mut Array<[T; N]>, &mut R)`, where `R: RandomSource`
+    pub fn shuffle(&mut self, rng: &mut (impl RandomSource + ?Sized)) {
+        random::partial_shuffle(&mut self.0, N, rng)
+    }
+
+    /// Like [`ArrayTools::shuffled`](trait.ArrayTools.html#method.shuffled), for any length.
+    ///
+    /// Type: `(Array<[T; N]>, &mut R) -> Array<[T; N]>`, where `R: RandomSource`
+    #[must_use = "this returns the shuffled array; use `.shuffle()` to shuffle in place"]
+    pub fn shuffled(mut self, rng: &mut (impl RandomSource + ?Sized)) -> Self {
+        self.shuffle(rng);
+        self
+    }
+
+    /// Like [`ArrayTools::choose_multiple`](trait.ArrayTools.html#method.choose_multiple), for any length.
+    ///
+    /// Type: `(Array<[T; N]>, &mut R) -> [T; K]`, where `R: RandomSource` and `K <= N`
+    ///
+    /// ```rust
+    /// use arraytools::{Array, RandomSource};
+    /// # struct Counter(u64);
+    /// # impl RandomSource for Counter {
+    /// #     fn next_u32(&mut self) -> u32 { self.next_u64() as u32 }
+    /// #     fn next_u64(&mut self) -> u64 { self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15); self.0 }
+    /// # }
+    /// # let mut rng = Counter(1);
+    ///
+    /// let deck = Array::<[u8; 52]>::numeric_indices();
+    /// let hand = deck.shuffled(&mut rng).choose_multiple::<5>(&mut rng);
+    /// assert!(hand.iter().all(|&card| card < 52));
+    /// ```
+    pub fn choose_multiple<const K: usize>(mut self, rng: &mut (impl RandomSource + ?Sized)) -> [T; K] {
+        const { assert!(K <= N, "cannot choose more elements than the array has") };
+        random::partial_shuffle(&mut self.0, K, rng);
+        match fill(&mut IntoIterator::into_iter(self.0)) {
+            Ok(chosen) => chosen,
+            Err(_) => unreachable!("the array has at least K elements"),
+        }
+    }
+
+    /// Like [`ArrayTools::combinations`](trait.ArrayTools.html#method.combinations), for any length.
+    ///
+    /// Type: `Array<[T; N]> -> impl Iterator<Item = [T; K]>`, where `T: Clone`
+    pub fn combinations<const K: usize>(self) -> Combinations<T, N, K>
+        where T: Clone
+    {
+        Combinations::new(self.0)
+    }
+
+    /// Like [`ArrayTools::permutations`](trait.ArrayTools.html#method.permutations), for any length.
+    ///
+    /// Type: `Array<[T; N]> -> impl Iterator<Item = [T; N]>`, where `T: Clone`
+    pub fn permutations(self) -> Permutations<T, N>
+        where T: Clone
+    {
+        Permutations::new(self.0)
+    }
+
+    /// Like [`ArrayTools::eq_mask`](trait.ArrayTools.html#method.eq_mask), for any length.
+    ///
+    /// Type: `(Array<[T; N]>, [T; N]) -> [bool; N]`, where `T: PartialEq`
+    ///
+    /// The masks work with [`MaskTools`](trait.MaskTools.html), which is
+    /// also implemented for every length.
+    ///
+    /// ```rust
+    /// use arraytools::{Array, MaskTools};
+    ///
+    /// let mut a = Array([0; 40]);
+    /// a[3] = 1;
+    /// assert_eq!(a.eq_mask([0; 40]).count_true(), 39);
+    /// ```
+    pub fn eq_mask(self, other: [T; N]) -> [bool; N]
+        where T: PartialEq
+    {
+        self.zip_with(other, |a, b| a == b)
+    }
+
+    /// Like [`ArrayTools::ne_mask`](trait.ArrayTools.html#method.ne_mask), for any length.
+    ///
+    /// Type: `(Array<[T; N]>, [T; N]) -> [bool; N]`, where `T: PartialEq`
+    pub fn ne_mask(self, other: [T; N]) -> [bool; N]
+        where T: PartialEq
+    {
+        self.zip_with(other, |a, b| a != b)
+    }
+
+    /// Like [`ArrayTools::lt_mask`](trait.ArrayTools.html#method.lt_mask), for any length.
+    ///
+    /// Type: `(Array<[T; N]>, [T; N]) -> [bool; N]`, where `T: PartialOrd`
+    pub fn lt_mask(self, other: [T; N]) -> [bool; N]
+        where T: PartialOrd
+    {
+        self.zip_with(other, |a, b| a < b)
+    }
+
+    /// Like [`ArrayTools::le_mask`](trait.ArrayTools.html#method.le_mask), for any length.
+    ///
+    /// Type: `(Array<[T; N]>, [T; N]) -> [bool; N]`, where `T: PartialOrd`
+    pub fn le_mask(self, other: [T; N]) -> [bool; N]
+        where T: PartialOrd
+    {
+        self.zip_with(other, |a, b| a <= b)
+    }
+
+    /// Like [`ArrayTools::gt_mask`](trait.ArrayTools.html#method.gt_mask), for any length.
+    ///
+    /// Type: `(Array<[T; N]>, [T; N]) -> [bool; N]`, where `T: PartialOrd`
+    pub fn gt_mask(self, other: [T; N]) -> [bool; N]
+        where T: PartialOrd
+    {
+        self.zip_with(other, |a, b| a > b)
+    }
+
+    /// Like [`ArrayTools::ge_mask`](trait.ArrayTools.html#method.ge_mask), for any length.
+    ///
+    /// Type: `(Array<[T; N]>, [T; N]) -> [bool; N]`, where `T: PartialOrd`
+    pub fn ge_mask(self, other: [T; N]) -> [bool; N]
+        where T: PartialOrd
+    {
+        self.zip_with(other, |a, b| a >= b)
+    }
+
+    /// Like [`ArrayTools::as_ref_array`](trait.ArrayTools.html#method.as_ref_array), for any length.
+    ///
+    /// Type: `&'a Array<[T; N]> -> [&'a T; N]`
+    pub fn as_ref_array(&self) -> [&T; N] {
+        self.0.each_ref()
+    }
+
+    /// Like [`ArrayTools::as_mut_array`](trait.ArrayTools.html#method.as_mut_array), for any length.
+    ///
+    /// Type: `&'a mut Array<[T; N]> -> [&'a mut T; N]`
+    pub fn as_mut_array(&mut self) -> [&mut T; N] {
+        self.0.each_mut()
+    }
+
+    /// Like [`ArrayTools::get_nth`](trait.ArrayTools.html#method.get_nth), for any length.
+    ///
+    /// Type: `&'a Array<[T; N]> -> &'a T`, where `I < N`
+    pub fn get_nth<const I: usize>(&self) -> &T {
+        const { assert!(I < N, "array index out of bounds") };
+        &self.0[I]
+    }
+
+    /// Like [`ArrayTools::get_nth_mut`](trait.ArrayTools.html#method.get_nth_mut), for any length.
+    ///
+    /// Type: `&'a mut Array<[T; N]> -> &'a mut T`, where `I < N`
+    pub fn get_nth_mut<const I: usize>(&mut self) -> &mut T {
+        const { assert!(I < N, "array index out of bounds") };
+        &mut self.0[I]
+    }
+
+    /// Like [`ArrayTools::into_nth`](trait.ArrayTools.html#method.into_nth), for any length.
+    ///
+    /// Type: `Array<[T; N]> -> T`, where `I < N`
+    pub fn into_nth<const I: usize>(self) -> T {
+        const { assert!(I < N, "array index out of bounds") };
+        match IntoIterator::into_iter(self.0).nth(I) {
+            Some(item) => item,
+            None => unreachable!("the array has more than I elements"),
+        }
+    }
+
+    /// Like [`ArrayTools::replace`](trait.ArrayTools.html#method.replace), for any length.
+    ///
+    /// Type: `(Array<[T; N]>, T) -> (Array<[T; N]>, T)`, where `I < N`
+    #[must_use = "this returns the new array; it doesn't update the existing one"]
+    pub fn replace<const I: usize>(mut self, value: T) -> (Self, T) {
+        const { assert!(I < N, "array index out of bounds") };
+        let old = mem::replace(&mut self.0[I], value);
+        (self, old)
+    }
+
+    /// Like [`ArrayTools::push_back`](trait.ArrayTools.html#method.push_back), for any length.
+    ///
+    /// Type: `(Array<[T; N]>, T) -> [T; M]`, where `M = N + 1`
+    ///
+    /// As stable Rust can't write `N + 1` in a type, the new length `M` is
+    /// a separate parameter, which is checked at compile time.  This is the
+    /// same for all the methods here that change the length.
+    ///
+    /// ```rust
+    /// use arraytools::Array;
+    ///
+    /// let a: [u8; 41] = Array([0; 40]).push_back(1);
+    /// assert_eq!(a[40], 1);
+    /// let (b, first) = Array(a).pop_front::<40>();
+    /// assert_eq!((b[39], first), (1, 0));
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// use arraytools::Array;
+    ///
+    /// let a: [u8; 40] = Array([0; 40]).push_back(1);
+    /// ```
+    #[must_use = "this returns the new array; it doesn't update the existing one"]
+    pub fn push_back<const M: usize>(self, item: T) -> [T; M] {
+        self.insert::<N, M>(item)
+    }
+
+    /// Like [`ArrayTools::push_front`](trait.ArrayTools.html#method.push_front), for any length.
+    ///
+    /// Type: `(Array<[T; N]>, T) -> [T; M]`, where `M = N + 1`
+    #[must_use = "this returns the new array; it doesn't update the existing one"]
+    pub fn push_front<const M: usize>(self, item: T) -> [T; M] {
+        self.insert::<0, M>(item)
+    }
+
+    /// Like [`ArrayTools::pop_back`](trait.ArrayTools.html#method.pop_back), for any non-zero length.
+    ///
+    /// Type: `Array<[T; N]> -> ([T; M], T)`, where `M = N - 1`
+    #[must_use = "this returns the new array; it doesn't update the existing one"]
+    pub fn pop_back<const M: usize>(self) -> ([T; M], T) {
+        self.remove::<M, M>()
+    }
+
+    /// Like [`ArrayTools::pop_front`](trait.ArrayTools.html#method.pop_front), for any non-zero length.
+    ///
+    /// Type: `Array<[T; N]> -> ([T; M], T)`, where `M = N - 1`
+    #[must_use = "this returns the new array; it doesn't update the existing one"]
+    pub fn pop_front<const M: usize>(self) -> ([T; M], T) {
+        self.remove::<0, M>()
+    }
+
+    /// Like [`ArrayTools::insert`](trait.ArrayTools.html#method.insert), for any length.
+    ///
+    /// Type: `(Array<[T; N]>, T) -> [T; M]`, where `I <= N` and `M = N + 1`
+    ///
+    /// ```rust
+    /// use arraytools::Array;
+    ///
+    /// let a = Array::<[u32; 40]>::numeric_indices().insert::<20, 41>(99);
+    /// assert_eq!(a[19..22], [19, 99, 20]);
+    /// let (b, removed) = Array(a).remove::<20, 40>();
+    /// assert_eq!((b, removed), (Array::numeric_indices().0, 99));
+    /// ```
+    #[must_use = "this returns the new array; it doesn't update the existing one"]
+    pub fn insert<const I: usize, const M: usize>(self, item: T) -> [T; M] {
+        const { assert!(I <= N, "insertion index out of bounds") };
+        const { assert!(M == N + 1, "the output length must be one more than the input length") };
+        let mut items = IntoIterator::into_iter(self.0);
+        let mut item = Some(item);
+        array::from_fn(|i| {
+            if i == I {
+                item.take()
+            } else {
+                items.next()
+            }
+            .unwrap()
+        })
+    }
+
+    /// Like [`ArrayTools::remove`](trait.ArrayTools.html#method.remove), for any non-zero length.
+    ///
+    /// Type: `Array<[T; N]> -> ([T; M], T)`, where `I < N` and `M = N - 1`
+    #[must_use = "this returns the new array; it doesn't update the existing one"]
+    pub fn remove<const I: usize, const M: usize>(self) -> ([T; M], T) {
+        const { assert!(I < N, "removal index out of bounds") };
+        const { assert!(M + 1 == N, "the output length must be one less than the input length") };
+        let mut items = IntoIterator::into_iter(self.0);
+        let mut removed = None;
+        let rest = array::from_fn(|i| {
+            if i == I {
+                removed = items.next();
+            }
+            items.next().unwrap()
+        });
+        // `I < N`, so if it wasn't before the end of the rest, it's the last one.
+        (rest, removed.or_else(|| items.next()).unwrap())
+    }
+
+    /// Like [`ArrayTools::to_le_bytes`](trait.ArrayTools.html#method.to_le_bytes), for any length.
+    ///
+    /// Type: `Array<[T; N]> -> [u8; M]`, where `M = N * size_of::<T>()`, for primitive integers and floats
+    ///
+    /// ```rust
+    /// use arraytools::Array;
+    ///
+    /// let bytes: [u8; 80] = Array([0x0102_u16; 40]).to_le_bytes();
+    /// assert_eq!(bytes[..4], [2, 1, 2, 1]);
+    /// assert_eq!(Array::<[u16; 40]>::from_be_bytes(bytes).0, [0x0201; 40]);
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// use arraytools::Array;
+    ///
+    /// let bytes: [u8; 40] = Array([0_u16; 40]).to_le_bytes();
+    /// ```
+    pub fn to_le_bytes<const M: usize>(self) -> [u8; M]
+        where T: NumberBytes
+    {
+        to_bytes(self.0, T::to_le_bytes)
+    }
+
+    /// Like [`ArrayTools::to_be_bytes`](trait.ArrayTools.html#method.to_be_bytes), for any length.
+    ///
+    /// Type: `Array<[T; N]> -> [u8; M]`, where `M = N * size_of::<T>()`, for primitive integers and floats
+    pub fn to_be_bytes<const M: usize>(self) -> [u8; M]
+        where T: NumberBytes
+    {
+        to_bytes(self.0, T::to_be_bytes)
+    }
+
+    /// Like [`ArrayTools::to_ne_bytes`](trait.ArrayTools.html#method.to_ne_bytes), for any length.
+    ///
+    /// Type: `Array<[T; N]> -> [u8; M]`, where `M = N * size_of::<T>()`, for primitive integers and floats
+    pub fn to_ne_bytes<const M: usize>(self) -> [u8; M]
+        where T: NumberBytes
+    {
+        to_bytes(self.0, T::to_ne_bytes)
+    }
+
+    /// Like [`ArrayTools::from_le_bytes`](trait.ArrayTools.html#method.from_le_bytes), for any length.
+    ///
+    /// Type: `[u8; M] -> Array<[T; N]>`, where `M = N * size_of::<T>()`, for primitive integers and floats
+    pub fn from_le_bytes<const M: usize>(bytes: [u8; M]) -> Self
+        where T: NumberBytes
+    {
+        Array(from_bytes(bytes, T::from_le_bytes))
+    }
+
+    /// Like [`ArrayTools::from_be_bytes`](trait.ArrayTools.html#method.from_be_bytes), for any length.
+    ///
+    /// Type: `[u8; M] -> Array<[T; N]>`, where `M = N * size_of::<T>()`, for primitive integers and floats
+    pub fn from_be_bytes<const M: usize>(bytes: [u8; M]) -> Self
+        where T: NumberBytes
+    {
+        Array(from_bytes(bytes, T::from_be_bytes))
+    }
+
+    /// Like [`ArrayTools::from_ne_bytes`](trait.ArrayTools.html#method.from_ne_bytes), for any length.
+    ///
+    /// Type: `[u8; M] -> Array<[T; N]>`, where `M = N * size_of::<T>()`, for primitive integers and floats
+    pub fn from_ne_bytes<const M: usize>(bytes: [u8; M]) -> Self
+        where T: NumberBytes
+    {
+        Array(from_bytes(bytes, T::from_ne_bytes))
+    }
+}
+
+impl<const N: usize> Array<[usize; N]> {
+    /// Like [`ArrayTools::indices`](trait.ArrayTools.html#method.indices), for any length.
+    ///
+    /// Type: `() -> Array<[usize; N]>`
+    pub fn indices() -> Self {
+        Array(array::from_fn(|i| i))
+    }
+}
+
+impl<const N: usize> Array<[bool; N]> {
+    /// Like [`BitTools::to_bits`](trait.BitTools.html#tymethod.to_bits).
+    ///
+    /// Type: `(Array<[bool; N]>, BitOrder) -> uM`, where `uM` is `u8`, `u16`, `u32`,
+    /// `u64` or `u128`, and `N <= 128`
+    pub fn to_bits(self, order: BitOrder) -> <[bool; N] as ArrayBits>::Bits
+        where [bool; N]: ArrayBits
+    {
+        self.0.to_bits(order)
+    }
+
+    /// Like [`BitTools::from_bits`](trait.BitTools.html#tymethod.from_bits).
+    ///
+    /// Type: `(uM, BitOrder) -> Array<[bool; N]>`, where `uM` is `u8`, `u16`, `u32`,
+    /// `u64` or `u128`, and `N <= 128`
+    pub fn from_bits(bits: <[bool; N] as ArrayBits>::Bits, order: BitOrder) -> Self
+        where [bool; N]: ArrayBits
+    {
+        Array(BitTools::from_bits(bits, order))
+    }
+
+    /// Like [`BitTools::pack_bits`](trait.BitTools.html#tymethod.pack_bits).
+    ///
+    /// Type: `(Array<[bool; N]>, BitOrder) -> [u8; M]`, where `M = (N + 7) / 8`
+    ///
+    /// ```rust
+    /// use arraytools::{Array, BitOrder};
+    ///
+    /// let flags = Array([true; 100]);
+    /// assert_eq!(flags.to_bits(BitOrder::LsbFirst).count_ones(), 100);
+    /// let packed: [u8; 13] = flags.pack_bits(BitOrder::MsbFirst);
+    /// assert_eq!(Array::unpack_bits(packed, BitOrder::MsbFirst), flags);
+    /// ```
+    pub fn pack_bits<const M: usize>(self, order: BitOrder) -> [u8; M] {
+        self.0.pack_bits(order)
+    }
+
+    /// Like [`BitTools::unpack_bits`](trait.BitTools.html#tymethod.unpack_bits).
+    ///
+    /// Type: `([u8; M], BitOrder) -> Array<[bool; N]>`, where `M = (N + 7) / 8`
+    pub fn unpack_bits<const M: usize>(packed: [u8; M], order: BitOrder) -> Self {
+        Array(BitTools::unpack_bits(packed, order))
+    }
+}
+
+fn to_bytes<T: NumberBytes, const N: usize, const M: usize>(array: [T; N], f: impl Fn(T) -> T::Bytes) -> [u8; M] {
+    const { assert!(M == N * mem::size_of::<T>(), "the output must have N * size_of::<T>() bytes") };
+    let mut bytes = [0; M];
+    for (chunk, x) in bytes.chunks_exact_mut(mem::size_of::<T>()).zip(array) {
+        chunk.copy_from_slice(f(x).as_ref());
+    }
+    bytes
+}
+
+fn from_bytes<T: NumberBytes, const N: usize, const M: usize>(bytes: [u8; M], f: impl Fn(T::Bytes) -> T) -> [T; N] {
+    const { assert!(M == N * mem::size_of::<T>(), "the input must have N * size_of::<T>() bytes") };
+    array::from_fn(|i| {
+        let mut x = T::Bytes::default();
+        x.as_mut().copy_from_slice(&bytes[i * mem::size_of::<T>()..][..mem::size_of::<T>()]);
+        f(x)
+    })
+}
+
+impl<A> Deref for Array<A> {
+    type Target = A;
+    fn deref(&self) -> &A {
+        &self.0
+    }
+}
+
+impl<A> DerefMut for Array<A> {
+    fn deref_mut(&mut self) -> &mut A {
+        &mut self.0
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for Array<[T; N]> {
+    fn from(array: [T; N]) -> Self {
+        Array(array)
+    }
+}
+
+impl<T, const N: usize> From<Array<[T; N]>> for [T; N] {
+    fn from(array: Array<[T; N]>) -> Self {
+        array.0
+    }
+}
+
+impl<T: Default, const N: usize> Default for Array<[T; N]> {
+    fn default() -> Self {
+        Array(array::from_fn(|_| T::default()))
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for Array<[T; N]> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.0[..], f)
+    }
+}
+
+impl<T: Hash, const N: usize> Hash for Array<[T; N]> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Hash::hash(&self.0[..], state)
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for Array<[T; N]> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0[..] == other.0[..]
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for Array<[T; N]> {}
+
+impl<T: PartialOrd, const N: usize> PartialOrd for Array<[T; N]> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        PartialOrd::partial_cmp(&self.0[..], &other.0[..])
+    }
+}
+
+impl<T: Ord, const N: usize> Ord for Array<[T; N]> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        Ord::cmp(&self.0[..], &other.0[..])
+    }
+}
+
+/// Collects exactly `N` items into an array.
+///
+/// Panics if the iterator has more or fewer than `N` items; to handle
+/// that case, use [`IteratorExt::collect_array`] instead.
+///
+/// [`IteratorExt::collect_array`]: trait.IteratorExt.html#method.collect_array
+///
+/// ```rust
+/// use arraytools::Array;
+///
+/// let squares: Array<[u64; 50]> = (0..50).map(|x| x * x).collect();
+/// assert_eq!(squares[49], 2401);
+/// ```
+impl<T, const N: usize> FromIterator<T> for Array<[T; N]> {
+    fn from_iter<I: IntoIterator<Item = T>>(it: I) -> Self {
+        let mut it = it.into_iter();
+        match fill(&mut it) {
+            Ok(array) if it.next().is_none() => Array(array),
+            _ => panic!("expected an iterator with exactly {} items", N),
+        }
+    }
+}
+
+impl<T, const N: usize> IntoIterator for Array<[T; N]> {
+    type Item = T;
+    type IntoIter = array::IntoIter<T, N>;
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIterator::into_iter(self.0)
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a Array<[T; N]> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut Array<[T; N]> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
+
+impl<T, I: SliceIndex<[T]>, const N: usize> Index<I> for Array<[T; N]> {
+    type Output = I::Output;
+    fn index(&self, index: I) -> &I::Output {
+        &self.0[..][index]
+    }
+}
+
+impl<T, I: SliceIndex<[T]>, const N: usize> IndexMut<I> for Array<[T; N]> {
+    fn index_mut(&mut self, index: I) -> &mut I::Output {
+        &mut self.0[..][index]
+    }
+}
+
+impl<T, const N: usize> AsRef<[T]> for Array<[T; N]> {
+    fn as_ref(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T, const N: usize> AsMut<[T]> for Array<[T; N]> {
+    fn as_mut(&mut self) -> &mut [T] {
+        &mut self.0
+    }
+}
+
+impl<T, const N: usize> Borrow<[T]> for Array<[T; N]> {
+    fn borrow(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T, const N: usize> BorrowMut<[T]> for Array<[T; N]> {
+    fn borrow_mut(&mut self) -> &mut [T] {
+        &mut self.0
+    }
+}